@@ -53,5 +53,11 @@ cmd="$RCMD cargo cnuT8"; echo "no_std, no-alloc, unsafe\n$" $cmd; $cmd
 cmd="$RCMD cargo t"; echo "tests\n$" $cmd; $cmd
 cmd="$RCMD cargo tu"; echo "tests, unsafe\n$" $cmd; $cmd
 
+# test the supported feature combinations (see the crate docs)
+for features in "" alloc alloc,libm no_std,alloc no_std,unsafe,libc no_std,safe std,safe; do
+	cmd="$RCMD cargo test --doc --no-default-features -F=$features"
+	echo "doc tests, [$features]\n$" $cmd; $cmd
+done
+
 # docs
 cmd="cargo +nightly nd"; echo "docs\n$" $cmd; $cmd
@@ -0,0 +1,100 @@
+// espera::arbitrary
+//
+//! Implementations of `proptest::arbitrary::Arbitrary`.
+//
+// The generated values are always valid. Besides the full range, the times
+// are biased towards the proleptic Gregorian years around the epoch, where
+// the calendar arithmetic has most of its edge cases.
+
+use crate::{
+    calendar::{Month, Weekday},
+    time::UnixTime,
+};
+use ::proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    strategy::{BoxedStrategy, Map, Strategy},
+};
+use core::ops::Range;
+
+/// Generates any `UnixTime`, biased towards the years `-9999` to `9999`.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime;
+/// use proptest::{arbitrary::any, test_runner::TestRunner};
+///
+/// // the round-trip through `to_ymdhms` is lossless within the range of
+/// // years representable by an `i32`, and saturates outside of it.
+/// let min = UnixTime::from_ymdhms(i32::MIN, 1, 1, 0, 0, 0).unwrap();
+/// let max = UnixTime::from_ymdhms(i32::MAX, 12, 31, 23, 59, 59).unwrap();
+///
+/// TestRunner::default()
+///     .run(&any::<UnixTime>(), |ut| {
+///         let (y, mo, d, h, mi, s) = ut.to_ymdhms();
+///         let round_trip = UnixTime::from_ymdhms(y, mo, d, h, mi, s).unwrap();
+///         assert_eq![ut.clamp(min, max), round_trip];
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+impl Arbitrary for UnixTime {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<UnixTime>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        // from -9999-01-01_00:00:00 to 9999-12-31_23:59:59
+        const AROUND_EPOCH: Range<i64> = -377_705_203_200..253_402_300_800;
+        prop_oneof![any::<i64>(), AROUND_EPOCH, -86_400 * 366..86_400 * 366_i64]
+            .prop_map(UnixTime::new)
+            .boxed()
+    }
+}
+
+/// Generates any `Month`.
+///
+/// # Examples
+/// ```
+/// use espera::all::Month;
+/// use proptest::{arbitrary::any, test_runner::TestRunner};
+///
+/// TestRunner::default()
+///     .run(&any::<Month>(), |m| {
+///         assert_eq![Ok(m), Month::from_number(m.number())];
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+impl Arbitrary for Month {
+    type Parameters = ();
+    type Strategy = Map<Range<usize>, fn(usize) -> Month>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (0..Month::COUNT).prop_map(Month::from_index_unchecked as fn(usize) -> Month)
+    }
+}
+
+/// Generates any `Weekday`.
+///
+/// # Examples
+/// ```
+/// use espera::all::Weekday;
+/// use proptest::{arbitrary::any, test_runner::TestRunner};
+///
+/// TestRunner::default()
+///     .run(&any::<Weekday>(), |w| {
+///         assert_eq![w, w.next_nth(7)];
+///         assert_eq![w, w.next().previous()];
+///         assert_eq![w, w.previous_nth(usize::MAX).next_nth(usize::MAX)];
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+impl Arbitrary for Weekday {
+    type Parameters = ();
+    type Strategy = Map<Range<usize>, fn(usize) -> Weekday>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (0..Weekday::COUNT).prop_map(Weekday::from_monday_index_unchecked as fn(usize) -> Weekday)
+    }
+}
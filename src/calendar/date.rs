@@ -0,0 +1,251 @@
+// espera::calendar::date
+//
+//! Calendar dates.
+//
+
+use crate::{
+    calendar::{civil_from_days, days_from_civil, is_leap_year, Month, Weekday},
+    error::{EsperaError, EsperaResult},
+};
+use core::fmt;
+
+/// A date in the proleptic Gregorian calendar.
+///
+/// The ordering is chronological.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    year: i32,
+    month: Month,
+    day: u8,
+}
+
+impl Date {
+    /// Returns a new `Date` from the given `year`, `month` and `day`.
+    ///
+    /// # Errors
+    /// Returns [`EsperaError::OutOfBounds`] if the `day` is not between 1 and
+    /// the length of the `month` in that `year`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month};
+    ///
+    /// assert![Date::new(2024, Month::February, 29).is_ok()];
+    /// assert![Date::new(2023, Month::February, 29).is_err()];
+    /// ```
+    pub const fn new(year: i32, month: Month, day: u8) -> EsperaResult<Date> {
+        if day < 1 || day > month.len(is_leap_year(year)) {
+            Err(EsperaError::OutOfBounds(
+                "The day must be between 1 and the length of the month.",
+            ))
+        } else {
+            Ok(Self { year, month, day })
+        }
+    }
+
+    /// Returns the year.
+    #[inline]
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month.
+    #[inline]
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    #[inline]
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the number of days since `1970-01-01`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month};
+    ///
+    /// assert_eq![0, Date::new(1970, Month::January, 1).unwrap().days_since_epoch()];
+    /// assert_eq![-1, Date::new(1969, Month::December, 31).unwrap().days_since_epoch()];
+    /// ```
+    #[inline]
+    pub const fn days_since_epoch(self) -> i64 {
+        days_from_civil(self.year, self.month.number(), self.day)
+    }
+
+    /// Returns the day of the week.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month, Weekday};
+    ///
+    /// assert_eq![Weekday::Thursday, Date::new(1970, Month::January, 1).unwrap().weekday()];
+    /// assert_eq![Weekday::Wednesday, Date::new(1969, Month::December, 31).unwrap().weekday()];
+    /// assert_eq![Weekday::Thursday, Date::new(2024, Month::February, 29).unwrap().weekday()];
+    /// ```
+    #[inline]
+    pub const fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_monday_index_unchecked((self.days_since_epoch() + 3).rem_euclid(7) as usize)
+    }
+
+    /// Returns the nearest date at or after `self` whose weekday is `target`.
+    ///
+    /// If `self` already falls on the `target` weekday, it's returned unchanged.
+    ///
+    /// # Panics
+    /// Panics if the resulting year doesn't fit in an `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month::*, Weekday::*};
+    ///
+    /// let thu = Date::new(2024, February, 29).unwrap();
+    /// assert_eq![thu, thu.next_weekday_on_or_after(Thursday)];
+    /// assert_eq![Date::new(2024, March, 4).unwrap(), thu.next_weekday_on_or_after(Monday)];
+    /// assert_eq![Date::new(2024, March, 6).unwrap(), thu.next_weekday_on_or_after(Wednesday)];
+    /// ```
+    pub const fn next_weekday_on_or_after(self, target: Weekday) -> Date {
+        let days = (target.index_from_monday() as i64 - self.weekday().index_from_monday() as i64)
+            .rem_euclid(7);
+        self.add_days(days)
+    }
+
+    /// Returns a new `Date` that is `days` days after `self` (or before, if
+    /// negative), or `None` if the resulting year doesn't fit in an `i32`.
+    ///
+    /// The days overflow into the adjacent months and years.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month};
+    ///
+    /// let d = Date::new(i32::MAX, Month::December, 31).unwrap();
+    /// assert![d.checked_add_days(1).is_none()];
+    /// assert![d.checked_add_days(i64::MAX).is_none()];
+    /// ```
+    pub const fn checked_add_days(self, days: i64) -> Option<Date> {
+        let Some(days) = self.days_since_epoch().checked_add(days) else {
+            return None;
+        };
+        let (year, month, day) = civil_from_days(days);
+        if year < i32::MIN as i64 || year > i32::MAX as i64 {
+            return None;
+        }
+        Some(Self {
+            year: year as i32,
+            month: Month::from_index_unchecked(month as usize - 1),
+            day,
+        })
+    }
+
+    /// Returns a new `Date` that is `days` days after `self` (or before, if
+    /// negative).
+    ///
+    /// The days overflow into the adjacent months and years.
+    ///
+    /// # Panics
+    /// Panics if the resulting year doesn't fit in an `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month::*};
+    ///
+    /// let d = Date::new(2023, December, 31).unwrap();
+    /// assert_eq![Date::new(2024, January, 1).unwrap(), d.add_days(1)];
+    /// assert_eq![Date::new(2024, February, 29).unwrap(), d.add_days(60)];
+    /// assert_eq![Date::new(2024, March, 1).unwrap(), d.add_days(61)];
+    /// assert_eq![Date::new(2023, February, 28).unwrap(), d.add_days(-306)];
+    /// assert_eq![d, d.add_days(366).add_days(-366)];
+    /// ```
+    pub const fn add_days(self, days: i64) -> Date {
+        if let Some(date) = self.checked_add_days(days) {
+            date
+        } else {
+            panic!("overflow when adding days to the date");
+        }
+    }
+
+    /// Returns a new `Date` that is `months` months after `self` (or before,
+    /// if negative), or `None` if the resulting year doesn't fit in an `i32`.
+    ///
+    /// See [`add_months`][Self::add_months] for the day clamping rule.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month};
+    ///
+    /// let d = Date::new(i32::MIN, Month::January, 31).unwrap();
+    /// assert![d.checked_add_months(-1).is_none()];
+    /// assert![d.checked_add_months(11).is_some()];
+    /// ```
+    pub const fn checked_add_months(self, months: i32) -> Option<Date> {
+        let total = self.year as i64 * 12 + self.month.index() as i64 + months as i64;
+        let year = total.div_euclid(12);
+        if year < i32::MIN as i64 || year > i32::MAX as i64 {
+            return None;
+        }
+        let year = year as i32;
+        let month = Month::from_index_unchecked(total.rem_euclid(12) as usize);
+        let len = month.len(is_leap_year(year));
+        let day = if self.day > len { len } else { self.day };
+        Some(Self { year, month, day })
+    }
+
+    /// Returns a new `Date` that is `months` months after `self` (or before,
+    /// if negative).
+    ///
+    /// The months overflow into the adjacent years, and the day is kept
+    /// unless it's past the end of the resulting month, in which case it's
+    /// clamped to the last day of that month. E.g. January 31 plus one month
+    /// is February 28, or February 29 in a leap year.
+    ///
+    /// Because of the clamping, adding months is not always reversible, nor
+    /// associative: adding one month twice can differ from adding two months.
+    ///
+    /// # Panics
+    /// Panics if the resulting year doesn't fit in an `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Date, Month::*};
+    ///
+    /// let jan31_2023 = Date::new(2023, January, 31).unwrap();
+    /// let jan31_2024 = Date::new(2024, January, 31).unwrap();
+    /// assert_eq![Date::new(2023, February, 28).unwrap(), jan31_2023.add_months(1)];
+    /// assert_eq![Date::new(2024, February, 29).unwrap(), jan31_2024.add_months(1)];
+    /// assert_eq![Date::new(2024, April, 30).unwrap(), jan31_2024.add_months(3)];
+    /// assert_eq![Date::new(2023, December, 31).unwrap(), jan31_2024.add_months(-1)];
+    ///
+    /// // the leap day is clamped in non-leap years
+    /// let feb29 = Date::new(2024, February, 29).unwrap();
+    /// assert_eq![Date::new(2025, February, 28).unwrap(), feb29.add_months(12)];
+    /// assert_eq![Date::new(2028, February, 29).unwrap(), feb29.add_months(48)];
+    ///
+    /// // clamping isn't reversible nor associative
+    /// assert_eq![Date::new(2024, January, 29).unwrap(), jan31_2024.add_months(1).add_months(-1)];
+    /// assert_eq![Date::new(2024, March, 29).unwrap(), jan31_2024.add_months(1).add_months(1)];
+    /// assert_eq![Date::new(2024, March, 31).unwrap(), jan31_2024.add_months(2)];
+    /// ```
+    pub const fn add_months(self, months: i32) -> Date {
+        if let Some(date) = self.checked_add_months(months) {
+            date
+        } else {
+            panic!("overflow when adding months to the date");
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year,
+            self.month.number(),
+            self.day
+        )
+    }
+}
@@ -0,0 +1,146 @@
+// espera::calendar::interop::chrono
+//
+//! Conversions with the `chrono` crate.
+//
+// The sub-second precision of `chrono` is truncated when converting into
+// the Unix time types, which only store whole seconds.
+
+use crate::{
+    calendar::{Month, Weekday},
+    error::{EsperaError, EsperaResult},
+    time::{UnixTime, UnixTime32},
+};
+use ::chrono::{DateTime, Month as CMonth, Utc, Weekday as CWeekday};
+use core::convert::TryFrom;
+
+/// Tries to convert a `UnixTime` into a `chrono` UTC date time.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`] if the time is outside the range
+/// supported by `chrono`.
+///
+/// # Examples
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use espera::all::UnixTime;
+///
+/// let ut = UnixTime::new(-915148799);
+/// let dt = DateTime::<Utc>::try_from(ut).unwrap();
+/// assert_eq![ut, UnixTime::from(dt)];
+/// assert![DateTime::<Utc>::try_from(UnixTime::MAX).is_err()];
+/// ```
+impl TryFrom<UnixTime> for DateTime<Utc> {
+    type Error = EsperaError;
+
+    fn try_from(ut: UnixTime) -> EsperaResult<DateTime<Utc>> {
+        DateTime::from_timestamp(ut.seconds, 0).ok_or(EsperaError::OutOfBounds(
+            "The time is outside the range supported by chrono.",
+        ))
+    }
+}
+
+/// Converts a `chrono` UTC date time into a `UnixTime`,
+/// truncating the sub-second precision.
+impl From<DateTime<Utc>> for UnixTime {
+    fn from(dt: DateTime<Utc>) -> UnixTime {
+        UnixTime::new(dt.timestamp())
+    }
+}
+
+/// Converts a `UnixTime32` into a `chrono` UTC date time.
+///
+/// # Examples
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use espera::all::UnixTime32;
+///
+/// let ut = UnixTime32::MAX;
+/// let dt = DateTime::<Utc>::from(ut);
+/// assert_eq![ut, UnixTime32::try_from(dt).unwrap()];
+/// assert![UnixTime32::try_from(DateTime::<Utc>::MIN_UTC).is_err()];
+/// ```
+impl From<UnixTime32> for DateTime<Utc> {
+    fn from(ut: UnixTime32) -> DateTime<Utc> {
+        // every u32 value of seconds is within the range supported by chrono
+        DateTime::from_timestamp(ut.seconds.into(), 0).unwrap_or_default()
+    }
+}
+
+/// Tries to convert a `chrono` UTC date time into a `UnixTime32`,
+/// truncating the sub-second precision.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`] if the time is outside the range
+/// of `UnixTime32`.
+impl TryFrom<DateTime<Utc>> for UnixTime32 {
+    type Error = EsperaError;
+
+    fn try_from(dt: DateTime<Utc>) -> EsperaResult<UnixTime32> {
+        u32::try_from(dt.timestamp())
+            .map(UnixTime32::new)
+            .map_err(|_| EsperaError::OutOfBounds("The time is outside the range of UnixTime32."))
+    }
+}
+
+/// # Examples
+/// ```
+/// use espera::calendar::Month;
+///
+/// for m in (0..12).map(Month::from_index_unchecked) {
+///     let cm = chrono::Month::from(m);
+///     assert_eq![m.name(), cm.name()];
+///     assert_eq![m, Month::from(cm)];
+/// }
+/// ```
+impl From<Month> for CMonth {
+    fn from(m: Month) -> CMonth {
+        match m {
+            Month::January => CMonth::January,
+            Month::February => CMonth::February,
+            Month::March => CMonth::March,
+            Month::April => CMonth::April,
+            Month::May => CMonth::May,
+            Month::June => CMonth::June,
+            Month::July => CMonth::July,
+            Month::August => CMonth::August,
+            Month::September => CMonth::September,
+            Month::October => CMonth::October,
+            Month::November => CMonth::November,
+            Month::December => CMonth::December,
+        }
+    }
+}
+impl From<CMonth> for Month {
+    fn from(m: CMonth) -> Month {
+        Month::from_index_unchecked(m.number_from_month() as usize - 1)
+    }
+}
+
+/// # Examples
+/// ```
+/// use espera::calendar::Weekday;
+///
+/// for w in (0..7).map(Weekday::from_monday_index_unchecked) {
+///     let cw = chrono::Weekday::from(w);
+///     assert_eq![w.abbr3(), format!["{cw}"]];
+///     assert_eq![w, Weekday::from(cw)];
+/// }
+/// ```
+impl From<Weekday> for CWeekday {
+    fn from(w: Weekday) -> CWeekday {
+        match w {
+            Weekday::Monday => CWeekday::Mon,
+            Weekday::Tuesday => CWeekday::Tue,
+            Weekday::Wednesday => CWeekday::Wed,
+            Weekday::Thursday => CWeekday::Thu,
+            Weekday::Friday => CWeekday::Fri,
+            Weekday::Saturday => CWeekday::Sat,
+            Weekday::Sunday => CWeekday::Sun,
+        }
+    }
+}
+impl From<CWeekday> for Weekday {
+    fn from(w: CWeekday) -> Weekday {
+        Weekday::from_monday_index_unchecked(w.num_days_from_monday() as usize)
+    }
+}
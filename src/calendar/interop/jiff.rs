@@ -0,0 +1,161 @@
+// espera::calendar::interop::jiff
+//
+//! Conversions with the `jiff` crate.
+//
+// `jiff` has nanosecond resolution, while the Unix time types only store
+// whole seconds, so the sub-second precision is lost converting into them.
+
+use crate::{
+    calendar::{Date, Month, Weekday},
+    error::{EsperaError, EsperaResult},
+    time::{UnixTime, UnixTime32},
+};
+use ::jiff::{civil, Timestamp};
+use core::convert::TryFrom;
+
+/// Tries to convert a `UnixTime` into a `jiff` timestamp.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`] if the time is outside the range
+/// supported by `jiff`.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime;
+/// use jiff::Timestamp;
+///
+/// let ut = UnixTime::new(-915148799);
+/// let ts = Timestamp::try_from(ut).unwrap();
+/// assert_eq![ut, UnixTime::from(ts)];
+/// assert![Timestamp::try_from(UnixTime::MAX).is_err()];
+/// ```
+impl TryFrom<UnixTime> for Timestamp {
+    type Error = EsperaError;
+
+    fn try_from(ut: UnixTime) -> EsperaResult<Timestamp> {
+        Timestamp::from_second(ut.seconds).map_err(|_| {
+            EsperaError::OutOfBounds("The time is outside the range supported by jiff.")
+        })
+    }
+}
+
+/// Converts a `jiff` timestamp into a `UnixTime`.
+///
+/// The sub-second precision is truncated toward zero, so a negative
+/// timestamp with fractional seconds rounds up to the next whole second.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime;
+/// use jiff::Timestamp;
+///
+/// let ts = Timestamp::new(-10, -999_999_999).unwrap();
+/// assert_eq![UnixTime::new(-10), UnixTime::from(ts)];
+/// ```
+impl From<Timestamp> for UnixTime {
+    fn from(ts: Timestamp) -> UnixTime {
+        UnixTime::new(ts.as_second())
+    }
+}
+
+/// Converts a `UnixTime32` into a `jiff` timestamp.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime32;
+/// use jiff::Timestamp;
+///
+/// let ut = UnixTime32::MAX;
+/// let ts = Timestamp::from(ut);
+/// assert_eq![ut, UnixTime32::try_from(ts).unwrap()];
+/// assert![UnixTime32::try_from(Timestamp::MIN).is_err()];
+/// ```
+impl From<UnixTime32> for Timestamp {
+    fn from(ut: UnixTime32) -> Timestamp {
+        // every u32 value of seconds is within the range supported by jiff
+        Timestamp::from_second(ut.seconds.into()).unwrap_or_default()
+    }
+}
+
+/// Tries to convert a `jiff` timestamp into a `UnixTime32`,
+/// truncating the sub-second precision toward zero.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`] if the time is outside the range
+/// of `UnixTime32`.
+impl TryFrom<Timestamp> for UnixTime32 {
+    type Error = EsperaError;
+
+    fn try_from(ts: Timestamp) -> EsperaResult<UnixTime32> {
+        u32::try_from(ts.as_second())
+            .map(UnixTime32::new)
+            .map_err(|_| EsperaError::OutOfBounds("The time is outside the range of UnixTime32."))
+    }
+}
+
+/// Tries to convert a `Date` into a `jiff` civil date.
+///
+/// Since `jiff` represents the months by their number, this is also the way
+/// to map a [`Month`] into `jiff`.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`] if the year is outside the range
+/// supported by `jiff`, from `-9999` to `9999`.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::{Date, Month};
+///
+/// let d = Date::new(2024, Month::February, 29).unwrap();
+/// let jd = jiff::civil::Date::try_from(d).unwrap();
+/// assert_eq![(2024, 2, 29), (jd.year(), jd.month(), jd.day())];
+/// assert_eq![d, Date::from(jd)];
+/// assert![jiff::civil::Date::try_from(Date::new(10_000, Month::May, 1).unwrap()).is_err()];
+/// ```
+impl TryFrom<Date> for civil::Date {
+    type Error = EsperaError;
+
+    fn try_from(d: Date) -> EsperaResult<civil::Date> {
+        const ERR: EsperaError =
+            EsperaError::OutOfBounds("The year is outside the range supported by jiff.");
+        let year = i16::try_from(d.year()).map_err(|_| ERR)?;
+        civil::Date::new(year, d.month().number() as i8, d.day() as i8).map_err(|_| ERR)
+    }
+}
+impl From<civil::Date> for Date {
+    fn from(d: civil::Date) -> Date {
+        Date::new(
+            d.year().into(),
+            Month::from_index_unchecked(d.month() as usize - 1),
+            d.day() as u8,
+        )
+        .expect("a valid jiff date is a valid date")
+    }
+}
+
+/// # Examples
+/// ```
+/// use espera::calendar::Weekday;
+///
+/// for w in (0..7).map(Weekday::from_monday_index_unchecked) {
+///     assert_eq![w, Weekday::from(jiff::civil::Weekday::from(w))];
+/// }
+/// ```
+impl From<Weekday> for civil::Weekday {
+    fn from(w: Weekday) -> civil::Weekday {
+        match w {
+            Weekday::Monday => civil::Weekday::Monday,
+            Weekday::Tuesday => civil::Weekday::Tuesday,
+            Weekday::Wednesday => civil::Weekday::Wednesday,
+            Weekday::Thursday => civil::Weekday::Thursday,
+            Weekday::Friday => civil::Weekday::Friday,
+            Weekday::Saturday => civil::Weekday::Saturday,
+            Weekday::Sunday => civil::Weekday::Sunday,
+        }
+    }
+}
+impl From<civil::Weekday> for Weekday {
+    fn from(w: civil::Weekday) -> Weekday {
+        Weekday::from_monday_index_unchecked(w.to_monday_zero_offset() as usize)
+    }
+}
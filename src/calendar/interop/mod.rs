@@ -0,0 +1,25 @@
+// espera::calendar::interop
+//
+//! Conversions with other date and time libraries.
+//!
+//! Each library is supported behind a feature of the same name:
+//!
+//! - `chrono`: [`UnixTime`], [`UnixTime32`], [`Month`] and [`Weekday`]
+//!   from and into `chrono::{DateTime<Utc>, Month, Weekday}`.
+//! - `jiff`: [`UnixTime`], [`UnixTime32`], [`Date`] and [`Weekday`]
+//!   from and into `jiff::{Timestamp, civil::Date, civil::Weekday}`.
+//!
+//! Both libraries have nanosecond resolution, which is truncated to whole
+//! seconds when converting into the Unix time types.
+//!
+//! [`Date`]: crate::calendar::Date
+//! [`UnixTime`]: crate::time::UnixTime
+//! [`UnixTime32`]: crate::time::UnixTime32
+//! [`Month`]: crate::calendar::Month
+//! [`Weekday`]: crate::calendar::Weekday
+//
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "jiff")]
+mod jiff;
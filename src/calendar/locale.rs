@@ -0,0 +1,35 @@
+// espera::calendar::locale
+//
+//! Localization of the calendar names.
+//
+
+use crate::calendar::{Month, Weekday};
+
+/// Provides the names of the months and weekdays in some language.
+///
+/// The [`Display`][core::fmt::Display] implementations of [`Month`] and
+/// [`Weekday`] always use [`English`]. Use [`Month::name_in`] and
+/// [`Weekday::name_in`] for other locales.
+pub trait Locale {
+    /// Returns the name of the given month.
+    fn month_name(&self, m: Month) -> &str;
+
+    /// Returns the name of the given weekday.
+    fn weekday_name(&self, w: Weekday) -> &str;
+}
+
+/// The default English [`Locale`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct English;
+
+impl Locale for English {
+    #[inline]
+    fn month_name(&self, m: Month) -> &str {
+        m.name()
+    }
+
+    #[inline]
+    fn weekday_name(&self, w: Weekday) -> &str {
+        w.name()
+    }
+}
@@ -1,12 +1,29 @@
 // espera::calendar
 //
-//! Month and Weekday types.
+//! Date, Month, Season and Weekday types, their sets, and their localization.
 //
 
+use crate::error::EsperaResult;
+
+mod date;
+mod locale;
 mod month;
+mod season;
+mod set;
 mod weekday;
 
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+#[cfg_attr(
+    feature = "nightly",
+    doc(cfg(any(feature = "chrono", feature = "jiff")))
+)]
+pub mod interop;
+
+pub use date::Date;
+pub use locale::{English, Locale};
 pub use month::Month;
+pub use season::Season;
+pub use set::{MonthSet, MonthSetIter, WeekdaySet, WeekdaySetIter};
 pub use weekday::Weekday;
 
 /// Returns `true` if the provided `year` is a leap year.
@@ -20,3 +37,102 @@ pub use weekday::Weekday;
 pub const fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
+
+/// Returns the number of days in the provided `year`: 366 if it's a leap
+/// year, or 365 otherwise.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::days_in_year;
+///
+/// assert_eq![366, days_in_year(2024)];
+/// assert_eq![365, days_in_year(2023)];
+/// assert_eq![365, days_in_year(1900)];
+/// assert_eq![366, days_in_year(2000)];
+/// ```
+#[inline]
+pub const fn days_in_year(year: i32) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Returns the nearest date at or after the given date whose weekday is
+/// `target`.
+///
+/// If the given date already falls on the `target` weekday, it's returned
+/// unchanged.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`][crate::error::EsperaError::OutOfBounds]
+/// if the `day` is not between 1 and the length of the `month` in that `year`.
+///
+/// # Panics
+/// Panics if the resulting year doesn't fit in an `i32`.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::{next_weekday_on_or_after, Date, Month::*, Weekday::*};
+///
+/// // 2023-12-28 was a Thursday
+/// assert_eq![
+///     Date::new(2024, January, 1).unwrap(),
+///     next_weekday_on_or_after(2023, December, 28, Monday).unwrap()
+/// ];
+/// assert_eq![
+///     Date::new(2023, December, 28).unwrap(),
+///     next_weekday_on_or_after(2023, December, 28, Thursday).unwrap()
+/// ];
+/// assert_eq![
+///     Date::new(2023, March, 1).unwrap(),
+///     next_weekday_on_or_after(2023, February, 23, Wednesday).unwrap()
+/// ];
+/// assert![next_weekday_on_or_after(2023, February, 29, Monday).is_err()];
+/// ```
+pub const fn next_weekday_on_or_after(
+    year: i32,
+    month: Month,
+    day: u8,
+    target: Weekday,
+) -> EsperaResult<Date> {
+    match Date::new(year, month, day) {
+        Ok(date) => Ok(date.next_weekday_on_or_after(target)),
+        Err(e) => Err(e),
+    }
+}
+
+// Returns the number of days since `1970-01-01`, for the given civil date.
+//
+// Based on <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+pub(crate) const fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let y = if month <= 2 {
+        year as i64 - 1
+    } else {
+        year as i64
+    };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let mp = (month as i64 + 9) % 12; // [0, 11], starting on March
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+// Returns the civil `(year, month, day)` for the given number of days
+// since `1970-01-01`. The year is not bounded to the range of an `i32`.
+//
+// Based on <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+pub(crate) const fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11], starting on March
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8; // [1, 12]
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
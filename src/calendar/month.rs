@@ -1,12 +1,28 @@
 // espera::calendar::month
 //
-//!
+//! Months of the year.
 //
 
-use core::{fmt, str::FromStr};
+use crate::{
+    calendar::{Locale, Season},
+    error::EsperaError,
+};
+use core::{convert::TryFrom, fmt, str::FromStr};
 use Month::*;
 
 /// The months.
+///
+/// The ordering follows the calendar, from January to December.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::Month::*;
+///
+/// assert![January < December];
+/// let mut months = [March, January, December, February];
+/// months.sort();
+/// assert_eq![[January, February, March, December], months];
+/// ```
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
 pub enum Month {
@@ -30,6 +46,16 @@ impl Month {
 
     /// Returns the length in days of the current month, taking into account
     /// whether it's a `leap` year, for february.
+    ///
+    /// See also [`days`][Self::days] and [`days_leap`][Self::days_leap].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{is_leap_year, Month};
+    ///
+    /// assert_eq![29, Month::February.len(is_leap_year(2024))];
+    /// assert_eq![28, Month::February.len(is_leap_year(2023))];
+    /// ```
     #[inline]
     #[allow(clippy::len_without_is_empty)]
     pub const fn len(self, leap: bool) -> u8 {
@@ -49,28 +75,158 @@ impl Month {
         }
     }
 
+    /// Returns the length in days of the current month in a common year,
+    /// which is 28 for february.
+    ///
+    /// It's the same as `len(false)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Month;
+    ///
+    /// assert_eq![28, Month::February.days()];
+    /// assert_eq![31, Month::January.days()];
+    /// ```
+    #[inline]
+    pub const fn days(self) -> u8 {
+        self.len(false)
+    }
+
+    /// Returns the length in days of the current month in a leap year,
+    /// which is 29 for february.
+    ///
+    /// It's the same as `len(true)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Month;
+    ///
+    /// assert_eq![29, Month::February.days_leap()];
+    /// assert_eq![30, Month::April.days_leap()];
+    /// ```
+    #[inline]
+    pub const fn days_leap(self) -> u8 {
+        self.len(true)
+    }
+
     /// Returns the previous month.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Month::*;
+    ///
+    /// assert_eq![January, February.previous()];
+    /// assert_eq![December, January.previous()];
+    /// ```
     #[inline(always)]
     pub const fn previous(self) -> Month {
         self.previous_nth(1)
     }
 
-    /// Returns the previous `nth` month.
+    /// Returns the previous `nth` month, wrapping around the year.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Month::*;
+    ///
+    /// assert_eq![March, March.previous_nth(0)];
+    /// assert_eq![December, March.previous_nth(3)];
+    /// assert_eq![March, March.previous_nth(12)];
+    /// assert_eq![December, January.previous_nth(13)];
+    /// assert_eq![October, January.previous_nth(usize::MAX)];
+    /// ```
     #[inline]
     pub const fn previous_nth(self, nth: usize) -> Month {
-        Self::from_index_unchecked(self.index().wrapping_sub(nth) % Self::COUNT)
+        Self::from_index_unchecked((self.index() + Self::COUNT - nth % Self::COUNT) % Self::COUNT)
     }
 
     /// Returns the next month.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Month::*;
+    ///
+    /// assert_eq![February, January.next()];
+    /// assert_eq![January, December.next()];
+    /// ```
     #[inline(always)]
     pub const fn next(self) -> Month {
         self.next_nth(1)
     }
 
-    /// Returns the next `nth` month.
+    /// Returns the next `nth` month, wrapping around the year.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Month::*;
+    ///
+    /// assert_eq![March, March.next_nth(0)];
+    /// assert_eq![February, November.next_nth(3)];
+    /// assert_eq![February, January.next_nth(13)];
+    /// assert_eq![April, January.next_nth(usize::MAX)];
+    ///
+    /// for nth in 0..36 {
+    ///     assert_eq![July, July.next_nth(nth).previous_nth(nth)];
+    /// }
+    /// ```
     #[inline]
     pub const fn next_nth(self, nth: usize) -> Month {
-        Self::from_index_unchecked(self.index().wrapping_add(nth) % Self::COUNT)
+        Self::from_index_unchecked((self.index() + nth % Self::COUNT) % Self::COUNT)
+    }
+
+    /// Returns the meteorological season of the month, either for the
+    /// `northern` or the southern hemisphere.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Month, Season};
+    ///
+    /// assert_eq![Season::Winter, Month::December.season(true)];
+    /// assert_eq![Season::Summer, Month::December.season(false)];
+    /// ```
+    #[inline]
+    pub const fn season(self, northern: bool) -> Season {
+        let season = match self {
+            March | April | May => Season::Spring,
+            June | July | August => Season::Summer,
+            September | October | November => Season::Autumn,
+            December | January | February => Season::Winter,
+        };
+        if northern {
+            season
+        } else {
+            season.opposite()
+        }
+    }
+
+    /// Returns `true` if the month is in the given `season`, either for the
+    /// `northern` or the southern hemisphere.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Month, Season};
+    ///
+    /// assert![Month::July.is_in_season(Season::Summer, true)];
+    /// assert![Month::July.is_in_season(Season::Winter, false)];
+    /// ```
+    #[inline]
+    pub const fn is_in_season(self, season: Season, northern: bool) -> bool {
+        self.season(northern) as u8 == season as u8
+    }
+
+    /// Returns the quarter of the year the month belongs to, from 1 to 4.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Month;
+    ///
+    /// assert_eq![1, Month::March.quarter()];
+    /// assert_eq![3, Month::July.quarter()];
+    /// assert_eq![4, Month::December.quarter()];
+    /// ```
+    #[inline]
+    pub const fn quarter(self) -> u8 {
+        self.index() as u8 / 3 + 1
     }
 
     /* numbers */
@@ -154,10 +310,130 @@ impl Month {
             _ => panic!("The month index must be between 0 and 11."),
         }
     }
+
+    /// Returns a `Month` from its index, wrapping it modulo 12,
+    /// so that it never fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Month;
+    ///
+    /// assert_eq![Month::January, Month::from_index_wrapping(0)];
+    /// assert_eq![Month::January, Month::from_index_wrapping(12)];
+    /// assert_eq![Month::February, Month::from_index_wrapping(13)];
+    /// assert_eq![Month::April, Month::from_index_wrapping(u8::MAX)];
+    ///
+    /// for month in (0..12).map(Month::from_index_unchecked) {
+    ///     assert_eq![month, Month::from_index_wrapping(u8::from(month))];
+    /// }
+    /// ```
+    #[inline]
+    pub const fn from_index_wrapping(index: u8) -> Self {
+        Self::from_index_unchecked(index as usize % Self::COUNT)
+    }
 }
 
 /// # abbreviations & representations
 impl Month {
+    /// Returns the English month name, in ASCII, UpperCamelCase.
+    pub const fn name(self) -> &'static str {
+        match self {
+            January => "January",
+            February => "February",
+            March => "March",
+            April => "April",
+            May => "May",
+            June => "June",
+            July => "July",
+            August => "August",
+            September => "September",
+            October => "October",
+            November => "November",
+            December => "December",
+        }
+    }
+
+    /// Returns the month name in the given `locale`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{English, Locale, Month, Weekday};
+    ///
+    /// struct French;
+    /// impl Locale for French {
+    ///     fn month_name(&self, m: Month) -> &str {
+    ///         ["janvier", "février", "mars", "avril", "mai", "juin", "juillet",
+    ///             "août", "septembre", "octobre", "novembre", "décembre"][m.index()]
+    ///     }
+    ///     fn weekday_name(&self, w: Weekday) -> &str {
+    ///         ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ///             "dimanche"][w.index_from_monday()]
+    ///     }
+    /// }
+    ///
+    /// assert_eq!["août", Month::August.name_in(&French)];
+    /// assert_eq!["August", Month::August.name_in(&English)];
+    /// ```
+    #[inline]
+    pub fn name_in(self, locale: &dyn Locale) -> &str {
+        locale.month_name(self)
+    }
+
+    /// Returns a `Month` from an ASCII string containing either the full
+    /// month name, or any of the month abbreviations, ignoring case.
+    ///
+    /// Since the 1-letter abbreviations of June and November coincide,
+    /// `"N"` is parsed as June.
+    ///
+    /// Returns `None` if the name is not recognized.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Month;
+    ///
+    /// const SEP: Option<Month> = Month::from_ascii(b"sept");
+    /// assert_eq![None, SEP];
+    /// assert_eq![Some(Month::September), Month::from_ascii(b"SEPTEMBER")];
+    /// assert_eq![Some(Month::September), Month::from_ascii(b"Sep")];
+    /// assert_eq![Some(Month::June), Month::from_ascii(b"n")];
+    ///
+    /// // parity with the `FromStr` implementation and the abbreviations
+    /// for m in (0..12).map(Month::from_index_unchecked) {
+    ///     for name in [m.name(), m.abbr3(), m.abbr2(), m.abbr1()] {
+    ///         let expected = if name == "N" { Month::June } else { m };
+    ///         assert_eq![Some(expected), Month::from_ascii(name.as_bytes())];
+    ///         assert_eq![Ok(expected), name.to_ascii_lowercase().parse()];
+    ///     }
+    /// }
+    /// ```
+    pub const fn from_ascii(bytes: &[u8]) -> Option<Month> {
+        // the longest name is "September"
+        let mut buf = [0; 9];
+        if bytes.len() > buf.len() {
+            return None;
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            buf[i] = bytes[i].to_ascii_lowercase();
+            i += 1;
+        }
+        match buf.split_at(bytes.len()).0 {
+            b"january" | b"jan" | b"ja" | b"j" => Some(January),
+            b"february" | b"feb" | b"fe" | b"f" => Some(February),
+            b"march" | b"mar" | b"mr" | b"r" => Some(March),
+            b"april" | b"apr" | b"ap" | b"p" => Some(April),
+            b"may" | b"my" | b"y" => Some(May),
+            b"june" | b"jun" | b"jn" | b"n" => Some(June),
+            b"july" | b"jul" | b"jl" | b"l" => Some(July),
+            b"august" | b"aug" | b"au" | b"u" => Some(August),
+            b"september" | b"sep" | b"se" | b"s" => Some(September),
+            b"october" | b"oct" | b"oc" | b"o" => Some(October),
+            b"november" | b"nov" | b"nv" => Some(November),
+            b"december" | b"dec" | b"de" | b"d" => Some(December),
+            _ => None,
+        }
+    }
+
     /// Returns the 3-letter abbreviated month name, in ASCII, UpperCamelCase.
     pub const fn abbr3(self) -> &'static str {
         match self {
@@ -389,20 +665,7 @@ impl Month {
 
 impl fmt::Display for Month {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            January => "January",
-            February => "February",
-            March => "March",
-            April => "April",
-            May => "May",
-            June => "June",
-            July => "July",
-            August => "August",
-            September => "September",
-            October => "October",
-            November => "November",
-            December => "December",
-        })
+        f.write_str(self.name())
     }
 }
 
@@ -412,115 +675,37 @@ impl From<Month> for u8 {
     }
 }
 
+/// Returns a `Month` from its counting number, from `January=1` to `December=12`.
+///
+/// Note that this differs from the `From<Month> for u8` conversion,
+/// which returns the month index, from `January=0` to `December=11`.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`] `if n < 1 || n > 12`.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::Month;
+///
+/// assert_eq![Month::January, Month::try_from(1).unwrap()];
+/// assert_eq![Month::December, 12_u8.try_into().unwrap()];
+/// assert![Month::try_from(0).is_err()];
+/// assert![Month::try_from(13).is_err()];
+/// ```
+impl TryFrom<u8> for Month {
+    type Error = EsperaError;
+
+    fn try_from(n: u8) -> Result<Month, Self::Error> {
+        Month::from_number(n).map_err(EsperaError::OutOfBounds)
+    }
+}
+
 /// Returns a `Month` from a string containing either the full month name,
 /// or any of the month ASCII abbreviations.
 impl FromStr for Month {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Month, Self::Err> {
-        if s.eq_ignore_ascii_case("January") {
-            Ok(January)
-        } else if s.eq_ignore_ascii_case("February") {
-            Ok(February)
-        } else if s.eq_ignore_ascii_case("March") {
-            Ok(March)
-        } else if s.eq_ignore_ascii_case("April") {
-            Ok(April)
-        } else if s.eq_ignore_ascii_case("May") {
-            Ok(May)
-        } else if s.eq_ignore_ascii_case("June") {
-            Ok(June)
-        } else if s.eq_ignore_ascii_case("July") {
-            Ok(July)
-        } else if s.eq_ignore_ascii_case("August") {
-            Ok(August)
-        } else if s.eq_ignore_ascii_case("September") {
-            Ok(September)
-        } else if s.eq_ignore_ascii_case("October") {
-            Ok(October)
-        } else if s.eq_ignore_ascii_case("November") {
-            Ok(November)
-        } else if s.eq_ignore_ascii_case("December") {
-            Ok(December)
-        //
-        } else if s.eq_ignore_ascii_case("Jan") {
-            Ok(January)
-        } else if s.eq_ignore_ascii_case("Feb") {
-            Ok(February)
-        } else if s.eq_ignore_ascii_case("Mar") {
-            Ok(March)
-        } else if s.eq_ignore_ascii_case("Apr") {
-            Ok(April)
-        // } else if s.eq_ignore_ascii_case("May") { // repeated
-        //     Ok(May)
-        } else if s.eq_ignore_ascii_case("Jun") {
-            Ok(June)
-        } else if s.eq_ignore_ascii_case("Jul") {
-            Ok(July)
-        } else if s.eq_ignore_ascii_case("Aug") {
-            Ok(August)
-        } else if s.eq_ignore_ascii_case("Sep") {
-            Ok(September)
-        } else if s.eq_ignore_ascii_case("Oct") {
-            Ok(October)
-        } else if s.eq_ignore_ascii_case("Nov") {
-            Ok(November)
-        } else if s.eq_ignore_ascii_case("Dec") {
-            Ok(December)
-        // abbr2
-        } else if s.eq_ignore_ascii_case("JA") {
-            Ok(January)
-        } else if s.eq_ignore_ascii_case("FE") {
-            Ok(February)
-        } else if s.eq_ignore_ascii_case("MR") {
-            Ok(March)
-        } else if s.eq_ignore_ascii_case("AP") {
-            Ok(April)
-        } else if s.eq_ignore_ascii_case("MY") {
-            Ok(May)
-        } else if s.eq_ignore_ascii_case("JN") {
-            Ok(June)
-        } else if s.eq_ignore_ascii_case("JL") {
-            Ok(July)
-        } else if s.eq_ignore_ascii_case("AU") {
-            Ok(August)
-        } else if s.eq_ignore_ascii_case("SE") {
-            Ok(September)
-        } else if s.eq_ignore_ascii_case("OC") {
-            Ok(October)
-        } else if s.eq_ignore_ascii_case("NV") {
-            Ok(November)
-        } else if s.eq_ignore_ascii_case("DE") {
-            Ok(December)
-        // abbr1
-        } else if s.eq_ignore_ascii_case("J") {
-            Ok(January)
-        } else if s.eq_ignore_ascii_case("F") {
-            Ok(February)
-        } else if s.eq_ignore_ascii_case("R") {
-            Ok(March)
-        } else if s.eq_ignore_ascii_case("P") {
-            Ok(April)
-        } else if s.eq_ignore_ascii_case("Y") {
-            Ok(May)
-        } else if s.eq_ignore_ascii_case("N") {
-            Ok(June)
-        } else if s.eq_ignore_ascii_case("L") {
-            Ok(July)
-        } else if s.eq_ignore_ascii_case("U") {
-            Ok(August)
-        } else if s.eq_ignore_ascii_case("S") {
-            Ok(September)
-        } else if s.eq_ignore_ascii_case("O") {
-            Ok(October)
-        } else if s.eq_ignore_ascii_case("N") {
-            Ok(November)
-        } else if s.eq_ignore_ascii_case("D") {
-            Ok(December)
-
-        //
-        } else {
-            Err("Invalid month name.")
-        }
+        Month::from_ascii(s.as_bytes()).ok_or("Invalid month name.")
     }
 }
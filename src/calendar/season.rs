@@ -0,0 +1,51 @@
+// espera::calendar::season
+//
+//! Seasons of the year.
+//
+
+use core::fmt;
+use Season::*;
+
+/// The seasons of the year.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Season {
+    Spring = 0,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// The number of seasons in a year.
+    pub const COUNT: usize = 4;
+
+    /// Returns the opposite season, as in the other hemisphere.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Season;
+    ///
+    /// assert_eq![Season::Summer, Season::Winter.opposite()];
+    /// ```
+    #[inline]
+    pub const fn opposite(self) -> Season {
+        match self {
+            Spring => Autumn,
+            Summer => Winter,
+            Autumn => Spring,
+            Winter => Summer,
+        }
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Spring => "Spring",
+            Summer => "Summer",
+            Autumn => "Autumn",
+            Winter => "Winter",
+        })
+    }
+}
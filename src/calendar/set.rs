@@ -0,0 +1,409 @@
+// espera::calendar::set
+//
+//! Compact sets of calendar items.
+//
+
+use crate::calendar::{Month, Weekday};
+use core::{fmt, iter::FromIterator};
+
+/// A set of [`Weekday`]s, backed by a `u8` bitmask.
+///
+/// It's useful for recurring schedules, like "every Monday, Wednesday and
+/// Friday". It's iterated in Monday-first order.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::{Weekday::*, WeekdaySet};
+///
+/// let set: WeekdaySet = [Friday, Monday, Wednesday].into_iter().collect();
+/// assert![set.contains(Monday)];
+/// assert![!set.contains(Tuesday)];
+/// assert_eq![3, set.len()];
+/// assert_eq![vec![Monday, Wednesday, Friday], set.iter().collect::<Vec<_>>()];
+///
+/// const MWF: WeekdaySet = WeekdaySet::new().with(Monday).with(Wednesday).with(Friday);
+/// assert_eq![set, MWF];
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WeekdaySet {
+    bits: u8,
+}
+
+impl WeekdaySet {
+    /// The empty set.
+    pub const EMPTY: WeekdaySet = WeekdaySet { bits: 0 };
+
+    /// The set with all the days of the week.
+    pub const ALL: WeekdaySet = WeekdaySet { bits: 0b111_1111 };
+
+    /// Returns a new empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Returns a set from the given `bits`, where the lowest bit is Monday.
+    ///
+    /// The highest bit is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Weekday::*, WeekdaySet};
+    ///
+    /// let set = WeekdaySet::from_bits(0b1100_0001);
+    /// assert_eq![WeekdaySet::new().with(Monday).with(Sunday), set];
+    /// assert_eq![0b100_0001, set.bits()];
+    /// ```
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self {
+            bits: bits & Self::ALL.bits,
+        }
+    }
+
+    /// Returns the bits of the set, where the lowest bit is Monday.
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.bits
+    }
+
+    /// Returns the set with the `day` added.
+    #[inline]
+    #[must_use]
+    pub const fn with(self, day: Weekday) -> Self {
+        Self {
+            bits: self.bits | Self::bit(day),
+        }
+    }
+
+    /// Returns the set with the `day` removed.
+    #[inline]
+    #[must_use]
+    pub const fn without(self, day: Weekday) -> Self {
+        Self {
+            bits: self.bits & !Self::bit(day),
+        }
+    }
+
+    /// Adds the `day` to the set.
+    ///
+    /// Returns `true` if the day was not already in the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Weekday::*, WeekdaySet};
+    ///
+    /// let mut set = WeekdaySet::new();
+    /// assert![set.insert(Tuesday)];
+    /// assert![!set.insert(Tuesday)];
+    /// assert![set.remove(Tuesday)];
+    /// assert![!set.remove(Tuesday)];
+    /// assert![set.is_empty()];
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, day: Weekday) -> bool {
+        let inserted = !self.contains(day);
+        *self = self.with(day);
+        inserted
+    }
+
+    /// Removes the `day` from the set.
+    ///
+    /// Returns `true` if the day was in the set.
+    #[inline]
+    pub fn remove(&mut self, day: Weekday) -> bool {
+        let removed = self.contains(day);
+        *self = self.without(day);
+        removed
+    }
+
+    /// Returns `true` if the set contains the `day`.
+    #[inline]
+    pub const fn contains(self, day: Weekday) -> bool {
+        self.bits & Self::bit(day) != 0
+    }
+
+    /// Returns the number of days in the set.
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns `true` if the set is empty.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns an iterator over the days in the set, in Monday-first order.
+    #[inline]
+    pub const fn iter(self) -> WeekdaySetIter {
+        WeekdaySetIter { bits: self.bits }
+    }
+
+    // Returns the bit of the given `day`.
+    #[inline]
+    const fn bit(day: Weekday) -> u8 {
+        1 << day.index_from_monday()
+    }
+}
+
+impl FromIterator<Weekday> for WeekdaySet {
+    fn from_iter<I: IntoIterator<Item = Weekday>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+impl IntoIterator for WeekdaySet {
+    type Item = Weekday;
+    type IntoIter = WeekdaySetIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for WeekdaySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the days of a [`WeekdaySet`], in Monday-first order.
+#[derive(Clone, Debug)]
+pub struct WeekdaySetIter {
+    bits: u8,
+}
+
+impl Iterator for WeekdaySetIter {
+    type Item = Weekday;
+
+    fn next(&mut self) -> Option<Weekday> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some(Weekday::from_monday_index_unchecked(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+impl ExactSizeIterator for WeekdaySetIter {}
+
+/// A set of [`Month`]s, backed by a `u16` bitmask.
+///
+/// It's useful for seasonal filters, like "only in the last quarter".
+/// It's iterated in calendar order.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::{Month::*, MonthSet};
+///
+/// let set: MonthSet = [December, June, January].into_iter().collect();
+/// assert![set.contains(June)];
+/// assert![!set.contains(July)];
+/// assert_eq![3, set.len()];
+/// assert_eq![vec![January, June, December], set.iter().collect::<Vec<_>>()];
+///
+/// const SUMMER: MonthSet = MonthSet::new().with(June).with(July).with(August);
+/// assert_eq![3, SUMMER.len()];
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MonthSet {
+    bits: u16,
+}
+
+impl MonthSet {
+    /// The empty set.
+    pub const EMPTY: MonthSet = MonthSet { bits: 0 };
+
+    /// The set with all the months of the year.
+    pub const ALL: MonthSet = MonthSet {
+        bits: 0b1111_1111_1111,
+    };
+
+    /// Returns a new empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Returns the set of the months in the given `quarter`, from 1 to 4.
+    ///
+    /// # Errors
+    /// `if quarter < 1 || quarter > 4`
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Month::*, MonthSet};
+    ///
+    /// let q4 = MonthSet::quarter(4).unwrap();
+    /// assert_eq![vec![October, November, December], q4.iter().collect::<Vec<_>>()];
+    /// assert![q4.iter().all(|m| m.quarter() == 4)];
+    /// assert_eq![vec![January, February, March], MonthSet::quarter(1).unwrap().iter().collect::<Vec<_>>()];
+    /// assert![MonthSet::quarter(0).is_err()];
+    /// assert![MonthSet::quarter(5).is_err()];
+    /// ```
+    #[inline]
+    pub const fn quarter(quarter: u8) -> Result<MonthSet, &'static str> {
+        if quarter < 1 || quarter > 4 {
+            Err("The quarter number must be between 1 and 4.")
+        } else {
+            Ok(Self {
+                bits: 0b111 << ((quarter - 1) * 3),
+            })
+        }
+    }
+
+    /// Returns a set from the given `bits`, where the lowest bit is January.
+    ///
+    /// The 4 highest bits are ignored.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self {
+            bits: bits & Self::ALL.bits,
+        }
+    }
+
+    /// Returns the bits of the set, where the lowest bit is January.
+    #[inline]
+    pub const fn bits(self) -> u16 {
+        self.bits
+    }
+
+    /// Returns the set with the `month` added.
+    #[inline]
+    #[must_use]
+    pub const fn with(self, month: Month) -> Self {
+        Self {
+            bits: self.bits | Self::bit(month),
+        }
+    }
+
+    /// Returns the set with the `month` removed.
+    #[inline]
+    #[must_use]
+    pub const fn without(self, month: Month) -> Self {
+        Self {
+            bits: self.bits & !Self::bit(month),
+        }
+    }
+
+    /// Adds the `month` to the set.
+    ///
+    /// Returns `true` if the month was not already in the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{Month::*, MonthSet};
+    ///
+    /// let mut set = MonthSet::new();
+    /// assert![set.insert(May)];
+    /// assert![!set.insert(May)];
+    /// assert![set.remove(May)];
+    /// assert![!set.remove(May)];
+    /// assert![set.is_empty()];
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, month: Month) -> bool {
+        let inserted = !self.contains(month);
+        *self = self.with(month);
+        inserted
+    }
+
+    /// Removes the `month` from the set.
+    ///
+    /// Returns `true` if the month was in the set.
+    #[inline]
+    pub fn remove(&mut self, month: Month) -> bool {
+        let removed = self.contains(month);
+        *self = self.without(month);
+        removed
+    }
+
+    /// Returns `true` if the set contains the `month`.
+    #[inline]
+    pub const fn contains(self, month: Month) -> bool {
+        self.bits & Self::bit(month) != 0
+    }
+
+    /// Returns the number of months in the set.
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns `true` if the set is empty.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns an iterator over the months in the set, in calendar order.
+    #[inline]
+    pub const fn iter(self) -> MonthSetIter {
+        MonthSetIter { bits: self.bits }
+    }
+
+    // Returns the bit of the given `month`.
+    #[inline]
+    const fn bit(month: Month) -> u16 {
+        1 << month.index()
+    }
+}
+
+impl FromIterator<Month> for MonthSet {
+    fn from_iter<I: IntoIterator<Item = Month>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+impl IntoIterator for MonthSet {
+    type Item = Month;
+    type IntoIter = MonthSetIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for MonthSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the months of a [`MonthSet`], in calendar order.
+#[derive(Clone, Debug)]
+pub struct MonthSetIter {
+    bits: u16,
+}
+
+impl Iterator for MonthSetIter {
+    type Item = Month;
+
+    fn next(&mut self) -> Option<Month> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some(Month::from_index_unchecked(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+impl ExactSizeIterator for MonthSetIter {}
@@ -1,14 +1,30 @@
 // espera::calendar::week
 //
-//!
+//! Days of the week.
 //
 
-use core::{fmt, str::FromStr};
+use crate::{calendar::Locale, error::EsperaError};
+use core::{convert::TryFrom, fmt, str::FromStr};
 use Weekday::*;
 
 /// The days of the week.
+///
+/// The ordering is Monday-based, following the ISO-8601 standard,
+/// so that Monday is the smallest day and Sunday the biggest.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::Weekday::{self, *};
+///
+/// assert![Monday < Sunday];
+/// assert![(Tuesday..=Thursday).contains(&Wednesday)];
+///
+/// let mut week = [Friday, Sunday, Tuesday, Monday, Saturday, Thursday, Wednesday];
+/// week.sort();
+/// assert_eq![[Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday], week];
+/// ```
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Weekday {
     /// The first day of the week, according to the ISO-8601 standard.
     ///
@@ -69,7 +85,9 @@ impl Weekday {
     /// Returns the previous `nth` weekday.
     #[inline]
     pub const fn previous_nth(self, nth: usize) -> Weekday {
-        Self::from_monday_index_unchecked(self.index_from_monday().wrapping_sub(nth) % Self::COUNT)
+        Self::from_monday_index_unchecked(
+            (self.index_from_monday() + Self::COUNT - nth % Self::COUNT) % Self::COUNT,
+        )
     }
 
     /// Returns the next weekday,
@@ -81,7 +99,38 @@ impl Weekday {
     /// Returns the next `nth` weekday.
     #[inline]
     pub const fn next_nth(self, nth: usize) -> Weekday {
-        Self::from_monday_index_unchecked(self.index_from_monday().wrapping_add(nth) % Self::COUNT)
+        Self::from_monday_index_unchecked(
+            (self.index_from_monday() + nth % Self::COUNT) % Self::COUNT,
+        )
+    }
+
+    /// Returns `true` if it's Saturday or Sunday.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Weekday;
+    ///
+    /// assert![Weekday::Saturday.is_weekend()];
+    /// assert![Weekday::Sunday.is_weekend()];
+    /// assert![!Weekday::Friday.is_weekend()];
+    /// ```
+    #[inline]
+    pub const fn is_weekend(self) -> bool {
+        matches![self, Saturday | Sunday]
+    }
+
+    /// Returns `true` if it's a working day, from Monday to Friday.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Weekday;
+    ///
+    /// assert![Weekday::Monday.is_weekday()];
+    /// assert![!Weekday::Sunday.is_weekday()];
+    /// ```
+    #[inline]
+    pub const fn is_weekday(self) -> bool {
+        !self.is_weekend()
     }
 }
 
@@ -154,6 +203,23 @@ impl Weekday {
             _ => panic!("The weekday number must be between 0 and 6."),
         }
     }
+
+    /// Returns a weekday from its index from `Monday=0`, wrapping it modulo 7,
+    /// so that it never fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Weekday;
+    ///
+    /// assert_eq![Weekday::Monday, Weekday::from_monday_index_wrapping(0)];
+    /// assert_eq![Weekday::Monday, Weekday::from_monday_index_wrapping(7)];
+    /// assert_eq![Weekday::Tuesday, Weekday::from_monday_index_wrapping(8)];
+    /// assert_eq![Weekday::Thursday, Weekday::from_monday_index_wrapping(u8::MAX)];
+    /// ```
+    #[inline]
+    pub const fn from_monday_index_wrapping(index: u8) -> Self {
+        Self::from_monday_index_unchecked(index as usize % Self::COUNT)
+    }
 }
 
 /// # from Sunday
@@ -235,6 +301,94 @@ impl Weekday {
 
 /// # abbreviations & representations
 impl Weekday {
+    /// Returns the English weekday name, in ASCII, UpperCamelCase.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Monday => "Monday",
+            Tuesday => "Tuesday",
+            Wednesday => "Wednesday",
+            Thursday => "Thursday",
+            Friday => "Friday",
+            Saturday => "Saturday",
+            Sunday => "Sunday",
+        }
+    }
+
+    /// Returns the weekday name in the given `locale`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::{English, Locale, Month, Weekday};
+    ///
+    /// struct German;
+    /// impl Locale for German {
+    ///     fn month_name(&self, m: Month) -> &str {
+    ///         ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli",
+    ///             "August", "September", "Oktober", "November", "Dezember"][m.index()]
+    ///     }
+    ///     fn weekday_name(&self, w: Weekday) -> &str {
+    ///         ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag",
+    ///             "Sonntag"][w.index_from_monday()]
+    ///     }
+    /// }
+    ///
+    /// assert_eq!["Mittwoch", Weekday::Wednesday.name_in(&German)];
+    /// assert_eq!["Wednesday", Weekday::Wednesday.name_in(&English)];
+    /// ```
+    #[inline]
+    pub fn name_in(self, locale: &dyn Locale) -> &str {
+        locale.weekday_name(self)
+    }
+
+    /// Returns a `Weekday` from an ASCII string containing either the full
+    /// weekday name, or any of the weekday abbreviations, ignoring case.
+    ///
+    /// The 1-letter abbreviation of Saturday is parsed from `"S"`.
+    ///
+    /// Returns `None` if the name is not recognized.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::calendar::Weekday;
+    ///
+    /// const WED: Option<Weekday> = Weekday::from_ascii(b"wed");
+    /// assert_eq![Some(Weekday::Wednesday), WED];
+    /// assert_eq![Some(Weekday::Thursday), Weekday::from_ascii(b"H")];
+    /// assert_eq![Some(Weekday::Saturday), Weekday::from_ascii(b"s")];
+    /// assert_eq![None, Weekday::from_ascii(b"Wednesdays")];
+    ///
+    /// // parity with the `FromStr` implementation and the abbreviations
+    /// for w in (0..7).map(Weekday::from_monday_index_unchecked) {
+    ///     for name in [w.name(), w.abbr3(), w.abbr2(), w.abbr1()] {
+    ///         let name = if name == "A" { "S" } else { name };
+    ///         assert_eq![Some(w), Weekday::from_ascii(name.as_bytes())];
+    ///         assert_eq![Ok(w), name.to_ascii_uppercase().parse()];
+    ///     }
+    /// }
+    /// ```
+    pub const fn from_ascii(bytes: &[u8]) -> Option<Weekday> {
+        // the longest name is "Wednesday"
+        let mut buf = [0; 9];
+        if bytes.len() > buf.len() {
+            return None;
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            buf[i] = bytes[i].to_ascii_lowercase();
+            i += 1;
+        }
+        match buf.split_at(bytes.len()).0 {
+            b"monday" | b"mon" | b"mo" | b"m" => Some(Monday),
+            b"tuesday" | b"tue" | b"tu" | b"t" => Some(Tuesday),
+            b"wednesday" | b"wed" | b"we" | b"w" => Some(Wednesday),
+            b"thursday" | b"thu" | b"th" | b"h" => Some(Thursday),
+            b"friday" | b"fri" | b"fr" | b"f" => Some(Friday),
+            b"saturday" | b"sat" | b"sa" | b"s" => Some(Saturday),
+            b"sunday" | b"sun" | b"su" | b"u" => Some(Sunday),
+            _ => None,
+        }
+    }
+
     /// Returns the 3-letter abbreviated weekday name, in ASCII, UpperCamelCase.
     pub fn abbr3(self) -> &'static str {
         match self {
@@ -384,15 +538,28 @@ impl Weekday {
 
 impl fmt::Display for Weekday {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Monday => "Monday",
-            Tuesday => "Tuesday",
-            Wednesday => "Wednesday",
-            Thursday => "Thursday",
-            Friday => "Friday",
-            Saturday => "Saturday",
-            Sunday => "Sunday",
-        })
+        f.write_str(self.name())
+    }
+}
+
+/// Returns a `Weekday` from its index, from `Monday=0` to `Sunday=6`.
+///
+/// # Errors
+/// Returns [`EsperaError::OutOfBounds`] `if index > 6`.
+///
+/// # Examples
+/// ```
+/// use espera::calendar::Weekday;
+///
+/// assert_eq![Weekday::Monday, Weekday::try_from(0).unwrap()];
+/// assert_eq![Weekday::Sunday, 6_u8.try_into().unwrap()];
+/// assert![Weekday::try_from(7).is_err()];
+/// ```
+impl TryFrom<u8> for Weekday {
+    type Error = EsperaError;
+
+    fn try_from(index: u8) -> Result<Weekday, Self::Error> {
+        Weekday::from_monday_index(index.into()).map_err(EsperaError::OutOfBounds)
     }
 }
 
@@ -402,70 +569,6 @@ impl FromStr for Weekday {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Weekday, Self::Err> {
-        // full name
-        if s.eq_ignore_ascii_case("Monday") {
-            Ok(Monday)
-        } else if s.eq_ignore_ascii_case("Tuesday") {
-            Ok(Tuesday)
-        } else if s.eq_ignore_ascii_case("Wednesday") {
-            Ok(Wednesday)
-        } else if s.eq_ignore_ascii_case("Thursday") {
-            Ok(Thursday)
-        } else if s.eq_ignore_ascii_case("Friday") {
-            Ok(Friday)
-        } else if s.eq_ignore_ascii_case("Saturday") {
-            Ok(Saturday)
-        } else if s.eq_ignore_ascii_case("Sunday") {
-            Ok(Sunday)
-        // abbr3
-        } else if s.eq_ignore_ascii_case("Mon") {
-            Ok(Monday)
-        } else if s.eq_ignore_ascii_case("Tue") {
-            Ok(Tuesday)
-        } else if s.eq_ignore_ascii_case("Wed") {
-            Ok(Wednesday)
-        } else if s.eq_ignore_ascii_case("Thu") {
-            Ok(Thursday)
-        } else if s.eq_ignore_ascii_case("Fri") {
-            Ok(Friday)
-        } else if s.eq_ignore_ascii_case("Sat") {
-            Ok(Saturday)
-        } else if s.eq_ignore_ascii_case("Sun") {
-            Ok(Sunday)
-        // abbr2
-        } else if s.eq_ignore_ascii_case("MO") {
-            Ok(Monday)
-        } else if s.eq_ignore_ascii_case("TU") {
-            Ok(Tuesday)
-        } else if s.eq_ignore_ascii_case("WE") {
-            Ok(Wednesday)
-        } else if s.eq_ignore_ascii_case("TH") {
-            Ok(Thursday)
-        } else if s.eq_ignore_ascii_case("FR") {
-            Ok(Friday)
-        } else if s.eq_ignore_ascii_case("SA") {
-            Ok(Saturday)
-        } else if s.eq_ignore_ascii_case("SU") {
-            Ok(Sunday)
-        // abbr1
-        } else if s.eq_ignore_ascii_case("M") {
-            Ok(Monday)
-        } else if s.eq_ignore_ascii_case("T") {
-            Ok(Tuesday)
-        } else if s.eq_ignore_ascii_case("W") {
-            Ok(Wednesday)
-        } else if s.eq_ignore_ascii_case("H") {
-            Ok(Thursday)
-        } else if s.eq_ignore_ascii_case("F") {
-            Ok(Friday)
-        } else if s.eq_ignore_ascii_case("S") {
-            Ok(Saturday)
-        } else if s.eq_ignore_ascii_case("U") {
-            Ok(Sunday)
-
-        //
-        } else {
-            Err("Invalid weekday name.")
-        }
+        Weekday::from_ascii(s.as_bytes()).ok_or("Invalid weekday name.")
     }
 }
@@ -0,0 +1,93 @@
+// espera::control::frame_loop
+//
+//! A ready-made frame loop.
+//
+
+use crate::all::{Duration, Instant, Looper, Rate, Sleeper};
+use core::ops::ControlFlow;
+
+/// A ready-made frame loop, that combines a [`Looper`] and a [`Sleeper`].
+///
+/// The [`Looper`]'s root rate sets the target pace of the frames.
+#[derive(Clone, Debug)]
+pub struct FrameLoop {
+    looper: Looper,
+    sleeper: Sleeper,
+}
+
+impl FrameLoop {
+    /// Returns a new frame loop with the given root `rate` and `sleeper`.
+    pub fn new(rate: Rate, sleeper: Sleeper) -> Self {
+        let mut looper = Looper::new();
+        *looper.mut_root_rate() = rate;
+        Self { looper, sleeper }
+    }
+
+    /// Returns a new frame loop with the given `ticks_per_second`,
+    /// and a default sleeper.
+    pub fn with_tps(ticks_per_second: f64) -> Self {
+        Self::new(Rate::with_tps(ticks_per_second), Sleeper::default())
+    }
+
+    /// Runs the loop until the `body` returns [`ControlFlow::Break`].
+    ///
+    /// Each iteration measures the frame, calls the `body` with the delta
+    /// duration since the previous frame, and then sleeps the remaining time
+    /// in order to maintain the pace of the root rate.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use espera::all::{Duration, FrameLoop, Instant};
+    ///
+    /// let mut fl = FrameLoop::with_tps(100.);
+    /// let mut frames = 0;
+    /// let start = Instant::now();
+    /// fl.run(|_delta| {
+    ///     frames += 1;
+    ///     if frames == 5 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    /// });
+    /// assert_eq![5, frames];
+    /// assert_eq![5, fl.ref_looper().ref_root_rate().ticks()];
+    /// // it slept 4 times for about 10 ms each
+    /// assert![Instant::now() - start >= Duration::milliseconds(36)];
+    /// ```
+    pub fn run(&mut self, mut body: impl FnMut(Duration) -> ControlFlow<()>) {
+        self.looper.reset_root();
+        loop {
+            let (_, delta) = self.looper.start_frame();
+
+            if body(delta).is_break() {
+                break;
+            }
+
+            let root = self.looper.ref_root_rate();
+            let remaining = root.duration() - root.last_elapsed(Instant::now());
+            self.sleeper.sleep(remaining);
+        }
+    }
+
+    /// Returns a reference to the looper.
+    #[inline]
+    pub fn ref_looper(&self) -> &Looper {
+        &self.looper
+    }
+
+    /// Returns an exclusive reference to the looper.
+    #[inline]
+    pub fn mut_looper(&mut self) -> &mut Looper {
+        &mut self.looper
+    }
+
+    /// Returns a reference to the sleeper.
+    #[inline]
+    pub fn ref_sleeper(&self) -> &Sleeper {
+        &self.sleeper
+    }
+
+    /// Returns an exclusive reference to the sleeper.
+    #[inline]
+    pub fn mut_sleeper(&mut self) -> &mut Sleeper {
+        &mut self.sleeper
+    }
+}
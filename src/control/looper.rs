@@ -3,10 +3,13 @@
 //! Loop manager with support for multiple rates.
 //
 
-use crate::all::{Duration, EsperaResult, Instant, Rate, RateStats};
-use ahash::AHashMap;
+use crate::all::{Duration, EsperaError, EsperaResult, Instant, Rate, RateStats};
 use sixbit::{DecodeSixbit, EncodeSixbit};
-use std::thread::sleep;
+use std::{
+    collections::{hash_map, HashMap},
+    hash::BuildHasher,
+    thread::sleep,
+};
 
 /// The status of a given [`Looper`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -16,8 +19,11 @@ pub enum LoopStatus {
 }
 
 /// A loop manager that supports multiple [`Rate`]s.
+///
+/// It's generic over the hasher `S` used for storing the rates,
+/// which defaults to [`ahash::RandomState`].
 #[derive(Clone, Debug)]
-pub struct Looper {
+pub struct Looper<S = ahash::RandomState> {
     /// The loop status.
     ///
     /// Forces to alternate between a single sleep period and an active period.
@@ -29,19 +35,30 @@ pub struct Looper {
     root_stats: RateStats,
 
     /// Custom rates.
-    rates: AHashMap<u128, Rate>,
+    rates: HashMap<u128, Rate, S>,
     /// Stats for the custom rates.
-    stats: AHashMap<u128, RateStats>,
+    stats: HashMap<u128, RateStats, S>,
+
+    /// The checkpoints of the current frame, followed by the unused ones of
+    /// previous frames, kept for reusing their labels' allocations.
+    checkpoints: Vec<(String, Duration)>,
+    /// The number of checkpoints of the current frame.
+    checkpoint_count: usize,
+    /// The instant of the last checkpoint of the current frame.
+    last_checkpoint: Option<Instant>,
 }
 
-impl Default for Looper {
+impl<S: Default> Default for Looper<S> {
     fn default() -> Self {
         Self {
             status: LoopStatus::Active,
             root_rate: Rate::default(),
             root_stats: RateStats::default(),
-            rates: AHashMap::new(),
-            stats: AHashMap::new(),
+            rates: HashMap::default(),
+            stats: HashMap::default(),
+            checkpoints: Vec::new(),
+            checkpoint_count: 0,
+            last_checkpoint: None,
         }
     }
 }
@@ -53,8 +70,35 @@ impl Looper {
     }
 }
 
-impl Looper {
+impl<S: Clone> Looper<S> {
+    /// Returns a new looper that uses the given `hash_builder` for its rates.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Looper, Rate};
+    /// use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+    ///
+    /// let mut l = Looper::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    /// l.add_rate("custom", Rate::with_tps(60.), true).unwrap();
+    /// assert![l.ref_rate("custom").is_some()];
+    /// ```
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            status: LoopStatus::Active,
+            root_rate: Rate::default(),
+            root_stats: RateStats::default(),
+            rates: HashMap::with_hasher(hash_builder.clone()),
+            stats: HashMap::with_hasher(hash_builder),
+            checkpoints: Vec::new(),
+            checkpoint_count: 0,
+            last_checkpoint: None,
+        }
+    }
+}
+
+impl<S: BuildHasher> Looper<S> {
     /// Returns the current loop state machine status.
+    #[must_use]
     pub fn status(&self) -> LoopStatus {
         self.status
     }
@@ -68,6 +112,7 @@ impl Looper {
     /// + `Asleep`:
     ///   - sets status to `Active`
     ///   - sets the last measure to *now*.
+    ///   - clears the [`checkpoints`][Self::checkpoints].
     /// + `Active`:
     ///   - Returns `None`.
     //
@@ -75,27 +120,201 @@ impl Looper {
     //
     pub fn measure(&mut self) -> Option<(Instant, Duration)> {
         match self.status {
-            LoopStatus::Asleep => {
-                let (now, delta) = self.now_delta();
+            LoopStatus::Asleep => Some(self.begin_frame()),
+            LoopStatus::Active => None,
+        }
+    }
+
+    /// Starts a new frame, whatever the current status, and takes a measure.
+    ///
+    /// Returns the *now* instant and the *delta* since the last measure was
+    /// taken, like [`measure`][Self::measure] does when the status is `Asleep`.
+    ///
+    /// This allows driving the frames without calling [`sleep`][Self::sleep],
+    /// e.g. when sleeping by other means.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Looper, LoopStatus};
+    ///
+    /// let mut l = Looper::new();
+    /// l.start_frame();
+    /// assert_eq![None, l.measure()];
+    /// let (_now, delta) = l.start_frame();
+    /// assert![delta >= Duration::ZERO];
+    /// assert_eq![LoopStatus::Active, l.status()];
+    /// assert_eq![2, l.frame()];
+    /// ```
+    pub fn start_frame(&mut self) -> (Instant, Duration) {
+        self.begin_frame()
+    }
 
-                self.root_rate.set_last_tick(now);
-                self.root_rate.increment_ticks();
-                self.status = LoopStatus::Active;
+    // Sets the status to `Active`, and measures the new frame.
+    fn begin_frame(&mut self) -> (Instant, Duration) {
+        let (now, delta) = self.now_delta();
 
-                /* root averages */
+        self.root_rate.set_last_tick(now);
+        self.root_rate.increment_ticks();
+        self.status = LoopStatus::Active;
+        self.clear_checkpoints();
 
-                let ns: u64 = delta.whole_nanoseconds() as u64;
-                self.root_stats.add_ns(ns);
-                self.root_stats.update(self.root_rate.ticks());
+        /* root averages */
 
-                Some((now, delta))
-            }
-            LoopStatus::Active => None,
+        let ns: u64 = delta.whole_nanoseconds() as u64;
+        self.root_stats.add_ns(ns);
+        self.root_stats.update(self.root_rate.ticks());
+
+        (now, delta)
+    }
+
+    /// Records a checkpoint with the given `label`, and returns the duration
+    /// since the previous checkpoint, or since the start of the frame.
+    ///
+    /// This allows profiling the sections of a frame. The checkpoints are
+    /// cleared when a new frame starts with [`measure`][Self::measure], and
+    /// when the root rate is reset.
+    ///
+    /// The labels are copied into strings that are reused across frames, so
+    /// they only allocate when a label doesn't fit in the one recorded in the
+    /// same position in a previous frame.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Looper};
+    /// use std::thread::sleep;
+    ///
+    /// let mut l = Looper::new();
+    /// sleep(std::time::Duration::from_millis(2));
+    /// let input = l.checkpoint("input");
+    /// sleep(std::time::Duration::from_millis(10));
+    /// let update = l.checkpoint("update");
+    ///
+    /// assert![input >= Duration::milliseconds(2)];
+    /// assert![update >= Duration::milliseconds(10)];
+    /// let labels: Vec<_> = l.checkpoints().iter().map(|(label, _)| label.as_str()).collect();
+    /// assert_eq![vec!["input", "update"], labels];
+    /// assert_eq![update, l.checkpoints()[1].1];
+    ///
+    /// // a new frame clears them
+    /// l.sleep(Duration::ZERO);
+    /// l.measure();
+    /// assert![l.checkpoints().is_empty()];
+    ///
+    /// // the labels can also be built at runtime
+    /// for entity in 0..3 {
+    ///     l.checkpoint(&format!["entity {entity}"]);
+    /// }
+    /// assert_eq!["entity 2", l.checkpoints()[2].0];
+    ///
+    /// // and a reset also clears them
+    /// l.reset();
+    /// assert![l.checkpoints().is_empty()];
+    /// ```
+    pub fn checkpoint(&mut self, label: &str) -> Duration {
+        let now = Instant::now();
+        let previous = self
+            .last_checkpoint
+            .unwrap_or_else(|| self.root_rate.last_tick());
+        let duration = now - previous;
+        if let Some(checkpoint) = self.checkpoints.get_mut(self.checkpoint_count) {
+            checkpoint.0.clear();
+            checkpoint.0.push_str(label);
+            checkpoint.1 = duration;
+        } else {
+            self.checkpoints.push((label.to_string(), duration));
         }
+        self.checkpoint_count += 1;
+        self.last_checkpoint = Some(now);
+        duration
+    }
+
+    // Clears the checkpoints of the current frame, keeping their labels.
+    fn clear_checkpoints(&mut self) {
+        self.checkpoint_count = 0;
+        self.last_checkpoint = None;
+    }
+
+    /// Returns the checkpoints of the current frame, with their labels and
+    /// durations, in the order they were recorded.
+    #[must_use]
+    #[inline]
+    pub fn checkpoints(&self) -> &[(String, Duration)] {
+        &self.checkpoints[..self.checkpoint_count]
+    }
+
+    /// Returns the wall-time the loop has been running at the `now` instant,
+    /// since the first tick of the root rate.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper};
+    ///
+    /// let mut l = Looper::new();
+    /// l.reset_root();
+    /// l.measure();
+    /// let first = l.uptime(Instant::now());
+    /// l.sleep(Duration::milliseconds(2));
+    /// l.measure();
+    /// let second = l.uptime(Instant::now());
+    /// assert![second > first];
+    /// assert_eq![2, l.root_ticks()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn uptime(&self, now: Instant) -> Duration {
+        self.root_rate.first_elapsed(now)
+    }
+
+    /// Returns the number of ticks of the root rate.
+    #[must_use]
+    #[inline]
+    pub fn root_ticks(&self) -> u64 {
+        self.root_rate.ticks()
+    }
+
+    /// Returns the current frame number, which is the number of
+    /// [`measure`][Self::measure]s taken, i.e. the [`root_ticks`][Self::root_ticks].
+    #[must_use]
+    #[inline]
+    pub fn frame(&self) -> u64 {
+        self.root_rate.ticks()
+    }
+
+    /// Returns the average duration of the last 16 frames, from the stats of
+    /// the root rate.
+    ///
+    /// It's updated every 16 frames, or on every frame until there are 16 of
+    /// them, when it's the average of the available ones.
+    /// It's zero before the first frame.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Looper};
+    ///
+    /// let mut l = Looper::new();
+    /// assert_eq![Duration::ZERO, l.avg_frame_time()];
+    /// for _ in 0..5 {
+    ///     assert![l.sleep(Duration::milliseconds(1))];
+    ///     l.measure();
+    /// }
+    /// assert![l.avg_frame_time() >= Duration::milliseconds(1)];
+    /// for _ in 5..16 {
+    ///     assert![l.sleep(Duration::milliseconds(1))];
+    ///     l.measure();
+    /// }
+    /// assert_eq![16, l.frame()];
+    /// assert![l.avg_frame_time() >= Duration::milliseconds(1)];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn avg_frame_time(&self) -> Duration {
+        let avg = self.root_stats.avg_ns(16).unwrap_or(0.);
+        Duration::nanoseconds(avg as i64)
     }
 
     /// Returns the current instant and the delta duration since last measure,
     /// calculated using that instant.
+    #[must_use]
     #[inline]
     pub fn now_delta(&self) -> (Instant, Duration) {
         let now = Instant::now();
@@ -111,11 +330,36 @@ impl Looper {
     // }
 
     /// Resets all the accumulated times and statistics.
-    // TODO
-    // MAYBE RENAME to reset_all?)
+    ///
+    /// It's an alias of [`reset_all`][Self::reset_all].
+    #[inline]
     pub fn reset(&mut self) {
+        self.reset_all();
+    }
+
+    /// Resets the accumulated times and statistics of the root rate
+    /// and of every custom rate.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_last_tick(start);
+    /// l.add_rate("physics", r, true).unwrap();
+    /// l.do_tick(start + Duration::milliseconds(10), "physics");
+    /// assert_eq![1, l.ref_rate("physics").unwrap().ticks()];
+    ///
+    /// l.reset_all();
+    /// assert_eq![0, l.ref_rate("physics").unwrap().ticks()];
+    /// assert_eq![0, l.root_ticks()];
+    /// ```
+    pub fn reset_all(&mut self) {
         self.reset_root();
-        // self.reset_rates(); // TODO
+        self.rates.values_mut().for_each(Rate::reset);
+        self.stats.values_mut().for_each(RateStats::reset);
     }
 
     /// Resets the accumulated times and statistics.
@@ -128,6 +372,7 @@ impl Looper {
         self.status = LoopStatus::Asleep;
         self.root_rate.reset();
         self.root_stats.reset();
+        self.clear_checkpoints();
     }
 
     /// Resets the accumulated times and statistics for all rates.
@@ -166,7 +411,45 @@ impl Looper {
         Ok(self.rates.insert(key, rate))
     }
 
+    /// Inserts a new rate into the looper, with the specificied `duration` per
+    /// tick, and with optional `stats`, as long as the `name` doesn't exist yet.
+    ///
+    /// Unlike [`add_rate`][Self::add_rate], it never replaces an existing rate.
+    ///
+    /// # Errors
+    /// Returns an error if the `name` is not valid, or
+    /// [`EsperaError::DuplicateRate`] if it already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{EsperaError, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// assert![l.insert_rate("physics", Rate::with_tps(60.), false).is_ok()];
+    /// assert![matches![
+    ///     l.insert_rate("physics", Rate::with_tps(30.), false),
+    ///     Err(EsperaError::DuplicateRate)
+    /// ]];
+    /// assert_eq![60., l.ref_rate("physics").unwrap().tps().round()];
+    ///
+    /// // while `add_rate` replaces it
+    /// assert![l.add_rate("physics", Rate::with_tps(30.), false).unwrap().is_some()];
+    /// assert_eq![30., l.ref_rate("physics").unwrap().tps().round()];
+    /// ```
+    pub fn insert_rate(&mut self, name: &str, rate: Rate, stats: bool) -> EsperaResult<()> {
+        let key = name.chars().encode_sixbit::<u128>()?;
+        if self.rates.contains_key(&key) {
+            return Err(EsperaError::DuplicateRate);
+        }
+        if stats {
+            let _prev_stats = self.stats.insert(key, RateStats::new());
+        }
+        self.rates.insert(key, rate);
+        Ok(())
+    }
+
     /// Returns a reference to the requested `name`d rate.
+    #[must_use]
     #[inline]
     pub fn ref_rate(&self, name: &str) -> Option<&Rate> {
         if let Ok(key) = name.chars().encode_sixbit::<u128>() {
@@ -186,6 +469,87 @@ impl Looper {
         }
     }
 
+    /// Returns a reference to the stats of the requested `name`d rate.
+    ///
+    /// Returns `None` if the rate is not found or doesn't have stats enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_last_tick(start);
+    /// l.add_rate("physics", r.clone(), true).unwrap();
+    /// l.add_rate("render", r, false).unwrap();
+    /// for ms in [10, 20, 32] {
+    ///     l.do_tick(start + Duration::milliseconds(ms), "physics");
+    /// }
+    /// let stats = l.ref_stats("physics").unwrap();
+    /// assert_eq![Some(Duration::milliseconds(12)), stats.last()];
+    /// assert![l.ref_stats("render").is_none()];
+    /// assert![l.ref_stats("missing").is_none()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn ref_stats(&self, name: &str) -> Option<&RateStats> {
+        if let Ok(key) = name.chars().encode_sixbit::<u128>() {
+            self.stats.get(&key)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the stats of the root rate.
+    #[must_use]
+    #[inline]
+    pub fn ref_root_stats(&self) -> &RateStats {
+        &self.root_stats
+    }
+
+    /// Returns an iterator over the custom rates, yielding their name,
+    /// the rate, and its stats if they are enabled, in arbitrary order.
+    ///
+    /// The same iterator is returned when iterating over a `&Looper`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// l.add_rate("physics", Rate::with_millis(10), true).unwrap();
+    /// l.add_rate("render", Rate::with_millis(16), true).unwrap();
+    /// l.add_rate("audio", Rate::with_millis(5), false).unwrap();
+    ///
+    /// let mut rates: Vec<_> = (&l)
+    ///     .into_iter()
+    ///     .map(|(name, rate, stats)| (name, rate.duration().whole_milliseconds(), stats.is_some()))
+    ///     .collect();
+    /// rates.sort();
+    /// assert_eq![
+    ///     vec![
+    ///         ("audio".into(), 5, false),
+    ///         ("physics".into(), 10, true),
+    ///         ("render".into(), 16, true),
+    ///     ],
+    ///     rates
+    /// ];
+    ///
+    /// for (name, rate, stats) in &l {
+    ///     assert_eq![Some(rate), l.ref_rate(&name)];
+    ///     assert_eq![stats.is_some(), l.ref_stats(&name).is_some()];
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> LooperIter<'_, S> {
+        LooperIter {
+            rates: self.rates.iter(),
+            stats: &self.stats,
+        }
+    }
+
     // MAYBE TODO: set_rate?
 
     /// Returns the duration of the fastest rate.
@@ -195,11 +559,36 @@ impl Looper {
     pub fn fastest_rate_duration(&mut self) -> Option<Duration> {
         self.rates
             .iter()
-            .min_by(|(_, a), (_, b)| a.duration().cmp(&b.duration()))
+            .min_by(|(_, a), (_, b)| a.cmp_by_duration(b))
             .map(|(_, r)| r.duration())
     }
 
+    /// Sets the duration of the root rate to the duration of the fastest rate.
+    ///
+    /// Does nothing if there are no configured rates.
+    ///
+    /// It must be called after adding or modifying the rates, since the root
+    /// rate doesn't follow later changes automatically.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// l.add_rate("slow", Rate::with_tps(60.), false).unwrap();
+    /// l.add_rate("fast", Rate::with_tps(120.), false).unwrap();
+    /// l.sync_root_to_fastest();
+    /// assert_eq![Rate::with_tps(120.).duration(), l.ref_root_rate().duration()];
+    /// ```
+    #[inline]
+    pub fn sync_root_to_fastest(&mut self) {
+        if let Some(duration) = self.fastest_rate_duration() {
+            self.root_rate.set_duration(duration);
+        }
+    }
+
     /// Returns a reference to the root rate.
+    #[must_use]
     #[inline]
     pub fn ref_root_rate(&self) -> &Rate {
         &self.root_rate
@@ -211,6 +600,43 @@ impl Looper {
         &mut self.root_rate
     }
 
+    /// Replaces the root rate with the given `rate`.
+    #[inline]
+    pub fn set_root_rate(&mut self, rate: Rate) {
+        self.root_rate = rate;
+    }
+
+    /// Sets the `ticks_per_second` of the root rate.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Looper};
+    ///
+    /// let mut l = Looper::new();
+    /// l.set_root_tps(30.0);
+    /// let duration = l.ref_root_rate().duration();
+    /// assert![(duration - Duration::microseconds(33_333)).abs() < Duration::microseconds(1)];
+    /// ```
+    #[inline]
+    pub fn set_root_tps(&mut self, ticks_per_second: f64) {
+        self.root_rate.set_tps(ticks_per_second);
+    }
+
+    /// Sets the `duration_per_tick` of the root rate.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Looper};
+    ///
+    /// let mut l = Looper::new();
+    /// l.set_root_duration(Duration::milliseconds(25));
+    /// assert_eq![40.0, l.ref_root_rate().tps()];
+    /// ```
+    #[inline]
+    pub fn set_root_duration(&mut self, duration_per_tick: Duration) {
+        self.root_rate.set_duration(duration_per_tick);
+    }
+
     /* ticks */
 
     /// Returns the duration between the last tick of the `name`d rate,
@@ -227,17 +653,14 @@ impl Looper {
     /// [`do_tick_fast`][Self::do_tick_fast], because it takes into account the
     /// accumulated lag, at the cost of being a little less performant.
     ///
-    /// The maximum lag taken into account is ± 2.1 s (±[`i32::MAX`] ns).
+    /// The maximum lag taken into account is the [`max_lag`][Rate::max_lag]
+    /// of the rate, which is ± 2.1 s ([`Rate::DEFAULT_MAX_LAG`]) by default,
+    /// and can be configured with [`set_max_lag`][Rate::set_max_lag].
     pub fn do_tick(&mut self, instant: Instant, name: &str) -> Option<Duration> {
         if let Ok(key) = name.chars().encode_sixbit::<u128>() {
             if let Some(rate) = self.rates.get_mut(&key) {
                 if let Some(delta) = rate.do_tick(instant) {
-                    // stats
-                    if let Some(stats) = self.stats.get_mut(&key) {
-                        let ns: u64 = delta.whole_nanoseconds() as u64;
-                        stats.add_ns(ns);
-                        stats.update(rate.ticks());
-                    }
+                    update_stats(self.stats.get_mut(&key), delta, rate.ticks());
 
                     // log::trace![
                     //     "{name:10}{rate} || Δ:{delta:.2}, TPS:{:.2}",
@@ -260,6 +683,259 @@ impl Looper {
         self.do_tick(Instant::now(), name)
     }
 
+    /// Calls the closure `f` with the delta duration if the `name`d rate
+    /// ticks at the given `instant`, using [`do_tick`][Self::do_tick].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_last_tick(start);
+    /// l.add_rate("physics", r, false).unwrap();
+    ///
+    /// // simulates 100 ms in steps of 1 ms
+    /// let mut count = 0;
+    /// for ms in 1..=100 {
+    ///     l.run_tick("physics", start + Duration::milliseconds(ms), |_delta| count += 1);
+    /// }
+    /// assert_eq![10, count];
+    /// ```
+    #[inline]
+    pub fn run_tick(&mut self, name: &str, instant: Instant, mut f: impl FnMut(Duration)) {
+        if let Some(delta) = self.do_tick(instant, name) {
+            f(delta);
+        }
+    }
+
+    /// Returns the time left from `now` until the `name`d rate is due to tick
+    /// again, which is negative if the tick is overdue.
+    ///
+    /// Returns `None` if the rate is not found.
+    ///
+    /// See [`Rate::time_to_next`].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_last_tick(start);
+    /// l.add_rate("physics", r, false).unwrap();
+    ///
+    /// let ms = Duration::milliseconds;
+    /// assert_eq![Some(ms(3)), l.time_to_next("physics", start + ms(7))];
+    /// assert_eq![Some(ms(-2)), l.time_to_next("physics", start + ms(12))];
+    /// assert_eq![None, l.time_to_next("missing", start)];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn time_to_next(&self, name: &str, now: Instant) -> Option<Duration> {
+        self.ref_rate(name).map(|rate| rate.time_to_next(now))
+    }
+
+    /// Returns the time left from `now` until the next tick of any rate is due,
+    /// to drive a single sleep per loop iteration.
+    ///
+    /// It's the minimum [`time_to_next`][Rate::time_to_next] over all the
+    /// custom rates and the root rate, where the overdue rates count as zero.
+    /// The root rate is only considered if its duration is positive.
+    ///
+    /// Returns `None` if there are no rates to consider.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// assert_eq![None, l.next_wakeup(start)];
+    ///
+    /// for (name, tps) in [("render", 60.), ("ai", 10.)] {
+    ///     let mut r = Rate::with_tps(tps);
+    ///     r.set_last_tick(start);
+    ///     l.add_rate(name, r, false).unwrap();
+    /// }
+    /// let frame = l.ref_rate("render").unwrap().duration();
+    /// assert_eq![Some(frame), l.next_wakeup(start)];
+    ///
+    /// // when a rate is overdue, there's no time to wait
+    /// let later = start + Duration::milliseconds(20);
+    /// assert_eq![Some(Duration::ZERO), l.next_wakeup(later)];
+    /// ```
+    #[must_use]
+    pub fn next_wakeup(&self, now: Instant) -> Option<Duration> {
+        let root = Some(&self.root_rate).filter(|rate| rate.duration().is_positive());
+        root.into_iter()
+            .chain(self.rates.values())
+            .map(|rate| rate.time_to_next(now).max(Duration::ZERO))
+            .min()
+    }
+
+    /// Returns `true` if the most recent delta of the `name`d rate exceeded
+    /// its target duration by more than `threshold`.
+    ///
+    /// Returns `false` if the rate is not found or doesn't have stats enabled,
+    /// since the most recent delta is read from its stats.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_last_tick(start);
+    /// l.add_rate("render", r, true).unwrap();
+    ///
+    /// l.do_tick(start + Duration::milliseconds(12), "render");
+    /// assert![!l.over_budget("render", Duration::milliseconds(5))];
+    ///
+    /// // injects an over-budget delta of 20 ms
+    /// l.do_tick(start + Duration::milliseconds(32), "render");
+    /// assert![l.over_budget("render", Duration::milliseconds(5))];
+    /// assert![!l.over_budget("missing", Duration::ZERO)];
+    /// ```
+    #[must_use]
+    pub fn over_budget(&self, name: &str, threshold: Duration) -> bool {
+        if let Ok(key) = name.chars().encode_sixbit::<u128>() {
+            if let (Some(rate), Some(stats)) = (self.rates.get(&key), self.stats.get(&key)) {
+                if let Some(delta) = stats.last() {
+                    return delta > rate.duration() + threshold;
+                }
+            }
+        }
+        false
+    }
+
+    /// Calls [`do_tick`][Self::do_tick], and if the rate ticks with a delta
+    /// that exceeds its target duration by more than `threshold`, calls
+    /// `on_overrun` with the excess over the target duration.
+    ///
+    /// Since it's evaluated on each tick, the callback fires once per overrun.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_last_tick(start);
+    /// l.add_rate("render", r, false).unwrap();
+    ///
+    /// let mut overruns = vec![];
+    /// let threshold = Duration::milliseconds(5);
+    /// for ms in [10, 20, 37, 47, 48] {
+    ///     let instant = start + Duration::milliseconds(ms);
+    ///     l.do_tick_budgeted(instant, "render", threshold, |excess| overruns.push(excess));
+    /// }
+    /// assert_eq![vec![Duration::milliseconds(7)], overruns];
+    /// ```
+    pub fn do_tick_budgeted(
+        &mut self,
+        instant: Instant,
+        name: &str,
+        threshold: Duration,
+        mut on_overrun: impl FnMut(Duration),
+    ) -> Option<Duration> {
+        let delta = self.do_tick(instant, name)?;
+        if let Some(rate) = self.ref_rate(name) {
+            let excess = delta - rate.duration();
+            if excess > threshold {
+                on_overrun(excess);
+            }
+        }
+        Some(delta)
+    }
+
+    /// Tries to [`do_tick`][Self::do_tick] every rate with the same `instant`.
+    ///
+    /// Returns the names and deltas of the rates that ticked, in no
+    /// particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// let (mut fast, mut slow) = (Rate::with_seconds(0.01), Rate::with_seconds(1.));
+    /// fast.set_last_tick(start);
+    /// slow.set_last_tick(start);
+    /// l.add_rate("fast", fast, true).unwrap();
+    /// l.add_rate("slow", slow, true).unwrap();
+    ///
+    /// let ticked = l.tick_all(start + Duration::milliseconds(20));
+    /// assert_eq![vec![("fast".to_string(), Duration::milliseconds(20))], ticked];
+    /// ```
+    pub fn tick_all(&mut self, instant: Instant) -> Vec<(String, Duration)> {
+        let mut ticked = vec![];
+        for (key, rate) in self.rates.iter_mut() {
+            if let Some(delta) = rate.do_tick(instant) {
+                update_stats(self.stats.get_mut(key), delta, rate.ticks());
+                ticked.push((key.decode_sixbit().collect::<String>(), delta));
+            }
+        }
+        ticked
+    }
+    /// Calls [`tick_all`][Self::tick_all] with `Instant::now()`.
+    #[inline(always)]
+    pub fn tick_all_now(&mut self) -> Vec<(String, Duration)> {
+        self.tick_all(Instant::now())
+    }
+
+    /// Drives all the rates through a scripted timeline of `instants`,
+    /// calling [`tick_all`][Self::tick_all] with each one, in order.
+    ///
+    /// Returns the names and deltas of the rates that ticked, in the order of
+    /// the instants, and sorted by name for the rates that ticked at the same
+    /// instant, so that the result is deterministic.
+    ///
+    /// For testing a single rate, the same pattern can be followed by calling
+    /// [`do_tick`][Self::do_tick] with scripted instants.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Looper, Rate};
+    ///
+    /// let mut l = Looper::new();
+    /// let start = Instant::now();
+    /// for (name, ms) in [("physics", 10), ("render", 25)] {
+    ///     let mut r = Rate::with_millis(ms);
+    ///     r.set_last_tick(start);
+    ///     l.add_rate(name, r, false).unwrap();
+    /// }
+    ///
+    /// let timeline: Vec<_> = (1..=5).map(|i| start + Duration::milliseconds(i * 10)).collect();
+    /// let ms = Duration::milliseconds;
+    /// assert_eq![
+    ///     vec![
+    ///         ("physics".into(), ms(10)),
+    ///         ("physics".into(), ms(10)),
+    ///         ("physics".into(), ms(10)),
+    ///         ("render".into(), ms(30)),
+    ///         ("physics".into(), ms(10)),
+    ///         ("physics".into(), ms(10)),
+    ///         ("render".into(), ms(20)),
+    ///     ],
+    ///     l.replay(&timeline)
+    /// ];
+    /// ```
+    pub fn replay(&mut self, instants: &[Instant]) -> Vec<(String, Duration)> {
+        let mut fired = vec![];
+        for instant in instants {
+            let mut ticked = self.tick_all(*instant);
+            ticked.sort_by(|a, b| a.0.cmp(&b.0));
+            fired.append(&mut ticked);
+        }
+        fired
+    }
+
     /// Returns the duration between the last tick of the `name`d rate,
     /// and the provided `instant`, as long as the duration is non-negative.
     ///
@@ -277,12 +953,7 @@ impl Looper {
         if let Ok(key) = name.chars().encode_sixbit::<u128>() {
             if let Some(rate) = self.rates.get_mut(&key) {
                 if let Some(delta) = rate.do_tick_fast(instant) {
-                    // stats
-                    if let Some(stats) = self.stats.get_mut(&key) {
-                        let ns: u64 = delta.whole_nanoseconds() as u64;
-                        stats.add_ns(ns);
-                        stats.update(rate.ticks());
-                    }
+                    update_stats(self.stats.get_mut(&key), delta, rate.ticks());
 
                     // log::trace![
                     //     "{name:10} {rate} || Δ:{delta:.2}, TPS:{:.2}",
@@ -349,17 +1020,35 @@ impl Looper {
     ///   - sets status to `Sleep`
     ///   - sleeps for requested duration.
     /// + `Sleep`:
-    ///   - Returns `None`.
+    ///   - does nothing.
+    ///
+    /// Returns `true` if it actually slept, which allows detecting skipped
+    /// sleeps, e.g. because of a negative remaining time.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Looper, LoopStatus};
+    ///
+    /// let mut l = Looper::new();
+    /// assert![!l.sleep(-Duration::MILLISECOND)];
+    /// assert_eq![LoopStatus::Asleep, l.status()];
+    ///
+    /// l.measure();
+    /// assert![l.sleep(Duration::MICROSECOND)];
+    /// assert![!l.sleep(Duration::MICROSECOND)]; // already asleep
+    /// ```
     //
     // IMPROVE: check minimum resolution?
-    pub fn sleep(&mut self, duration: Duration) {
+    pub fn sleep(&mut self, duration: Duration) -> bool {
         if let LoopStatus::Active = self.status {
             self.status = LoopStatus::Asleep;
             if duration.is_positive() {
                 // log::debug!["sleep: {duration}"];
                 sleep(duration.unsigned_abs());
+                return true;
             }
         }
+        false
     }
 
     // MAYBE
@@ -372,3 +1061,48 @@ impl Looper {
     //     todo![];
     // }
 }
+
+// Adds the `delta` of a rate that ticked to its `stats`, if it has them,
+// and updates them with its new `ticks` count.
+fn update_stats(stats: Option<&mut RateStats>, delta: Duration, ticks: u64) {
+    if let Some(stats) = stats {
+        stats.add_ns(delta.whole_nanoseconds() as u64);
+        stats.update(ticks);
+    }
+}
+
+/// An iterator over the custom rates of a [`Looper`].
+///
+/// It's returned by [`Looper::iter`], and yields the decoded name of each
+/// rate, the rate, and its stats if they are enabled.
+#[derive(Debug)]
+pub struct LooperIter<'a, S> {
+    rates: hash_map::Iter<'a, u128, Rate>,
+    stats: &'a HashMap<u128, RateStats, S>,
+}
+
+impl<'a, S: BuildHasher> Iterator for LooperIter<'a, S> {
+    type Item = (String, &'a Rate, Option<&'a RateStats>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, rate) = self.rates.next()?;
+        let name = key.decode_sixbit().collect::<String>();
+        Some((name, rate, self.stats.get(key)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rates.size_hint()
+    }
+}
+impl<S: BuildHasher> ExactSizeIterator for LooperIter<'_, S> {}
+
+impl<'a, S: BuildHasher> IntoIterator for &'a Looper<S> {
+    type Item = (String, &'a Rate, Option<&'a RateStats>);
+    type IntoIter = LooperIter<'a, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -1,10 +1,36 @@
 // espera::control
 //
 //! Time control.
+//!
+//! All the types in this module are `Send` and `Sync`, so they can be moved
+//! and shared between threads.
 //
 
+mod frame_loop;
 mod looper;
+mod pacer;
 mod rate;
 mod sleeper;
+mod stopwatch;
+mod timer;
 
-pub use {looper::*, rate::*, sleeper::*};
+pub use {frame_loop::*, looper::*, pacer::*, rate::*, sleeper::*, stopwatch::*, timer::*};
+
+// Ensures at compile time the types remain `Send` and `Sync`.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<FrameLoop>();
+    assert_send_sync::<Looper>();
+    assert_send_sync::<LoopStatus>();
+    assert_send_sync::<Pacer>();
+    assert_send_sync::<Rate>();
+    assert_send_sync::<RateSnapshot>();
+    assert_send_sync::<RateStats>();
+    assert_send_sync::<Sleeper>();
+    assert_send_sync::<SleepReport>();
+    assert_send_sync::<SleepStrategy>();
+    assert_send_sync::<Stopwatch>();
+    assert_send_sync::<TickId>();
+    assert_send_sync::<Timer>();
+    assert_send_sync::<WindowStats>();
+};
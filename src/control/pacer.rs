@@ -0,0 +1,197 @@
+// espera::control::pacer
+//
+//! A frame pacing controller.
+//
+
+use crate::all::{Duration, Instant, Rate, RateStats, SleepStrategy, Sleeper};
+
+/// A frame pacing controller, that combines a [`Rate`] and a [`Sleeper`].
+///
+/// Each call to [`pace`][Self::pace] blocks until the next tick scheduled by
+/// the rate, so that a loop calling it runs at a steady pace.
+///
+/// The ticks are scheduled from the [`first_tick`][Rate::first_tick] of the
+/// rate, so that the errors of each sleep don't accumulate over time.
+///
+/// # Adaptive mode
+/// When [`set_adaptive`][Self::set_adaptive] is enabled, the pacer records
+/// how early each frame finishes its work, and every 16 ticks adjusts the
+/// strategy of the sleeper: when the frames finish on average with more than
+/// half a tick to spare, it switches to [`SleepStrategy::Yield`], which saves
+/// power, and otherwise it switches to [`SleepStrategy::SpinHint`], which is
+/// more accurate under load.
+#[derive(Clone, Debug)]
+pub struct Pacer {
+    rate: Rate,
+    sleeper: Sleeper,
+    /// The time to spare of the recent frames, when in adaptive mode.
+    slack: Option<RateStats>,
+}
+
+impl Pacer {
+    /// Returns a new pacer with the given `rate` and `sleeper`.
+    ///
+    /// The first tick is scheduled one tick duration after the
+    /// [`first_tick`][Rate::first_tick] of the `rate`.
+    pub fn new(rate: Rate, sleeper: Sleeper) -> Self {
+        Self {
+            rate,
+            sleeper,
+            slack: None,
+        }
+    }
+
+    /// Returns a new pacer with the given `ticks_per_second`,
+    /// and a default sleeper.
+    pub fn with_tps(ticks_per_second: f64) -> Self {
+        Self::new(Rate::with_tps(ticks_per_second), Sleeper::default())
+    }
+
+    /// Blocks until the next scheduled tick, and returns the actual duration
+    /// since the previous tick.
+    ///
+    /// If the next tick was already due by more than a whole tick duration,
+    /// it doesn't sleep, and the schedule is restarted from now, instead of
+    /// rushing through all the missed ticks.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Pacer};
+    ///
+    /// let start = Instant::now();
+    /// let mut p = Pacer::with_tps(200.);
+    /// let mut total = Duration::ZERO;
+    /// for _ in 0..10 {
+    ///     total += p.pace();
+    /// }
+    /// assert_eq![10, p.ref_rate().ticks()];
+    /// assert![Instant::now() - start >= Duration::milliseconds(50)];
+    ///
+    /// // the average delta is close to 5 ms
+    /// let avg = total / 10;
+    /// assert![avg >= Duration::microseconds(4_500)];
+    /// assert![avg < Duration::milliseconds(15)];
+    ///
+    /// // when running late, it catches up without rushing
+    /// std::thread::sleep(std::time::Duration::from_millis(20));
+    /// p.pace(); // doesn't sleep
+    /// assert![p.pace() >= Duration::microseconds(4_500)];
+    /// assert_eq![12, p.ref_rate().ticks()];
+    /// ```
+    pub fn pace(&mut self) -> Duration {
+        let now = Instant::now();
+        match self
+            .rate
+            .instant_tick_checked(self.rate.ticks().saturating_add(1))
+        {
+            Some(target) if target - now > -self.rate.duration() => {
+                self.adapt(target - now);
+                self.sleeper.sleep(target - now);
+            }
+            // too late, or the schedule is not representable:
+            // restarts the schedule from now, keeping the ticks count
+            _ => {
+                self.adapt(Duration::ZERO);
+                let ticks = self.rate.ticks() as f64;
+                let scheduled =
+                    Duration::saturating_seconds_f64(self.rate.duration().as_seconds_f64() * ticks);
+                if let Some(first_tick) = now.checked_sub(scheduled) {
+                    self.rate.set_first_tick(first_tick);
+                } else {
+                    self.rate.set_first_tick(now);
+                    self.rate.set_ticks(0);
+                }
+            }
+        }
+        let now = Instant::now();
+        let delta = self.rate.last_elapsed(now);
+        self.rate.increment_ticks();
+        self.rate.set_last_tick(now);
+        delta
+    }
+
+    /// Returns `true` if the adaptive mode is enabled.
+    #[inline]
+    pub fn is_adaptive(&self) -> bool {
+        self.slack.is_some()
+    }
+
+    /// Enables or disables the [adaptive mode](#adaptive-mode).
+    ///
+    /// Disabling it leaves the sleeper with its current strategy.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Pacer, Rate, SleepStrategy, Sleeper};
+    ///
+    /// // sustained early completion switches to yielding
+    /// let mut p = Pacer::new(Rate::with_millis(2), Sleeper::new(100_000, true));
+    /// p.set_adaptive(true);
+    /// assert![p.is_adaptive()];
+    /// for _ in 0..16 {
+    ///     p.pace();
+    /// }
+    /// assert_eq![SleepStrategy::Yield, p.ref_sleeper().strategy()];
+    ///
+    /// // frames that barely make it switch back to spinning
+    /// for _ in 0..16 {
+    ///     std::thread::sleep(std::time::Duration::from_micros(1_900));
+    ///     p.pace();
+    /// }
+    /// assert_eq![SleepStrategy::SpinHint, p.ref_sleeper().strategy()];
+    /// ```
+    pub fn set_adaptive(&mut self, adaptive: bool) {
+        self.slack = adaptive.then(RateStats::new);
+    }
+
+    // Records the time to spare of the current frame and, every 16 ticks,
+    // updates the sleep strategy if in adaptive mode.
+    fn adapt(&mut self, slack: Duration) {
+        let Some(stats) = self.slack.as_mut() else {
+            return;
+        };
+        stats.add(slack);
+        let tick = self.rate.ticks().saturating_add(1);
+        if tick % 16 == 0 {
+            stats.update(tick);
+            let avg = stats.avg_ns(16).unwrap_or(0.);
+            let strategy = if avg > self.rate.duration().whole_nanoseconds() as f64 / 2. {
+                SleepStrategy::Yield
+            } else {
+                SleepStrategy::SpinHint
+            };
+            if strategy != self.sleeper.strategy() {
+                self.sleeper.set_strategy(strategy);
+            }
+        }
+    }
+
+    /// Restarts the schedule from now, and resets the ticks counter.
+    pub fn reset(&mut self) {
+        self.rate.reset();
+    }
+
+    /// Returns a reference to the rate.
+    #[inline]
+    pub fn ref_rate(&self) -> &Rate {
+        &self.rate
+    }
+
+    /// Returns an exclusive reference to the rate.
+    #[inline]
+    pub fn mut_rate(&mut self) -> &mut Rate {
+        &mut self.rate
+    }
+
+    /// Returns a reference to the sleeper.
+    #[inline]
+    pub fn ref_sleeper(&self) -> &Sleeper {
+        &self.sleeper
+    }
+
+    /// Returns an exclusive reference to the sleeper.
+    #[inline]
+    pub fn mut_sleeper(&mut self) -> &mut Sleeper {
+        &mut self.sleeper
+    }
+}
@@ -5,5 +5,10 @@
 
 mod rate;
 mod stats;
+mod tick;
 
-pub use {rate::Rate, stats::RateStats};
+pub use {
+    rate::{OnBackwards, Rate, RateSnapshot},
+    stats::{RateStats, WindowStats},
+    tick::{TickId, TickIndex},
+};
@@ -1,16 +1,26 @@
 // espera::rate::rate
 //
-//!
+//! A periodic rate of ticks.
 //
 
-use crate::all::{Duration, Instant};
+use crate::all::{
+    timecode_ns_u64, Duration, EsperaError, EsperaResult, Instant, TickId, TickIndex,
+};
 
 /// A rate allows to control a periodic repetition in time.
 ///
+/// It doesn't implement `Ord`, since that would depend on its live
+/// [`Instant`] fields. Use [`cmp_by_duration`][Self::cmp_by_duration]
+/// for ordering rates by their speed.
+///
+/// Its `PartialEq` implementation is also time-sensitive, since it compares
+/// every field, including the live [`Instant`]s. Use
+/// [`same_config`][Self::same_config] for comparing only the configuration.
+///
 // Note that when duration is ZERO it will be ignored in practice.
 //
-// Size: 60 Bytes = 16 + 16 + 16 + 8 + 4
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+// Size: 80 Bytes = 16 + 16 + 16 + 8 + 8 + 8 + 1 + 7 of padding
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Rate {
     /// Target duration per tick.
     ///
@@ -26,16 +36,48 @@ pub struct Rate {
     /// Saves the difference of the delta time against the target duration,
     /// allowing to automatically compensate lag.
     ///
-    // Storing the nanoseconds in a i32 allows us to store
-    // up to 2 seconds of either positive or negative lag.
-    delta_rem: i32,
+    // Storing the nanoseconds in a i64 allows us to store
+    // up to 292 years of either positive or negative lag.
+    delta_rem: i64,
+    /// The maximum absolute value of `delta_rem`, in nanoseconds.
+    max_lag: i64,
+
+    /// What to do when a tick sees an instant before the last tick.
+    on_backwards: OnBackwards,
     // MAYBE: Whether to allocate and manage associated stats.
     // stats: bool,
     // ...
+}
 
-    // 4 bytes more to reach 64B
-    // e.g. 2xbool 1xu16
-    // e.g. 1xu32
+/// What a [`Rate`] does when ticking with an instant before its last tick,
+/// e.g. because the clock went backwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OnBackwards {
+    /// Doesn't tick, and keeps the last tick unchanged.
+    ///
+    /// Note that if the clock was permanently skewed backwards the rate
+    /// will not tick again until it catches up with the last tick.
+    #[default]
+    Ignore,
+    /// Doesn't tick, but sets the last tick to the given instant,
+    /// so that the next tick happens one period after it.
+    Resync,
+    /// Ticks with a zero delta, and sets the last tick to the given instant.
+    TreatAsZero,
+}
+
+/// A cheap copyable snapshot of the progress of a [`Rate`].
+///
+/// It's `Send` and `Sync`, so it can be shared with other threads
+/// (e.g. through a channel) without locking the original rate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateSnapshot {
+    /// Number of ticks recorded.
+    pub ticks: u64,
+    /// The instant of the last tick.
+    pub last_tick: Instant,
+    /// Target duration per tick.
+    pub duration: Duration,
 }
 
 impl Default for Rate {
@@ -47,11 +89,16 @@ impl Default for Rate {
             last_tick: Instant::now(),
             ticks: 0,
             delta_rem: 0,
+            max_lag: Self::DEFAULT_MAX_LAG.whole_nanoseconds() as i64,
+            on_backwards: OnBackwards::Ignore,
         }
     }
 }
 
 impl Rate {
+    /// The default maximum accumulated lag, of ± 2.1 s (±[`i32::MAX`] ns).
+    pub const DEFAULT_MAX_LAG: Duration = Duration::nanoseconds(i32::MAX as i64);
+
     /// Returns a new `Rate` with the given `duration_per_tick`.
     ///
     /// # Examples
@@ -59,6 +106,11 @@ impl Rate {
     /// use espera::all::{Duration, Rate};
     ///
     /// let r = Rate::new(Duration::milliseconds(25));
+    ///
+    /// // using the time constants
+    /// use espera::time::FRAME_60HZ;
+    /// let r = Rate::new(FRAME_60HZ);
+    /// assert![(r.tps() - 60.).abs() < 0.001];
     /// ```
     pub fn new(duration_per_tick: Duration) -> Self {
         Self {
@@ -67,6 +119,8 @@ impl Rate {
             last_tick: Instant::now(),
             ticks: 0,
             delta_rem: 0,
+            max_lag: Self::DEFAULT_MAX_LAG.whole_nanoseconds() as i64,
+            on_backwards: OnBackwards::Ignore,
         }
     }
 
@@ -84,6 +138,14 @@ impl Rate {
 
     /// Returns a new `Rate` with the given `ticks_per_second`.
     ///
+    /// See [`try_with_tps`][Self::try_with_tps] for a non-panicking version.
+    ///
+    /// # Panics
+    /// Panics if `ticks_per_second` is `0.0`, `NaN`, or so small that the
+    /// duration per tick overflows. A negative value results in a negative
+    /// duration, which never ticks in practice, and an infinite value
+    /// results in a zero duration.
+    ///
     /// # Examples
     /// ```
     /// use espera::all::Rate;
@@ -94,6 +156,77 @@ impl Rate {
         Self::new(Duration::seconds_f64(1.0 / ticks_per_second))
     }
 
+    /// Tries to return a new `Rate` with the given `ticks_per_second`.
+    ///
+    /// # Errors
+    /// Returns [`EsperaError::OutOfBounds`] if `ticks_per_second` is not
+    /// finite, not positive, or so small that the duration per tick overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Rate};
+    ///
+    /// let r = Rate::try_with_tps(40.0).unwrap();
+    /// assert_eq![Duration::milliseconds(25), r.duration()];
+    ///
+    /// assert![Rate::try_with_tps(0.0).is_err()];
+    /// assert![Rate::try_with_tps(-60.0).is_err()];
+    /// assert![Rate::try_with_tps(f64::NAN).is_err()];
+    /// assert![Rate::try_with_tps(f64::INFINITY).is_err()];
+    /// assert![Rate::try_with_tps(1e-300).is_err()];
+    /// ```
+    pub fn try_with_tps(ticks_per_second: f64) -> EsperaResult<Self> {
+        duration_from_tps(ticks_per_second).map(Self::new)
+    }
+
+    /// Returns a new `Rate` with the given `milliseconds_per_tick`.
+    ///
+    /// Values greater than [`i64::MAX`] are saturated.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Rate;
+    ///
+    /// assert_eq![Rate::with_tps(40.0).duration(), Rate::with_millis(25).duration()];
+    /// ```
+    pub fn with_millis(milliseconds_per_tick: u64) -> Self {
+        Self::new(Duration::milliseconds(
+            milliseconds_per_tick.min(i64::MAX as u64) as i64,
+        ))
+    }
+
+    /// Returns a new `Rate` with the given `microseconds_per_tick`.
+    ///
+    /// Values greater than [`i64::MAX`] are saturated.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Rate;
+    ///
+    /// assert_eq![Rate::with_tps(40.0).duration(), Rate::with_micros(25_000).duration()];
+    /// ```
+    pub fn with_micros(microseconds_per_tick: u64) -> Self {
+        Self::new(Duration::microseconds(
+            microseconds_per_tick.min(i64::MAX as u64) as i64,
+        ))
+    }
+
+    /// Returns a new `Rate` from a [`core::time::Duration`] per tick.
+    ///
+    /// Durations that can't be represented by [`Duration`] (greater than
+    /// [`i64::MAX`] seconds) are saturated to [`Duration::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Rate;
+    ///
+    /// let r = Rate::from_std(core::time::Duration::from_millis(25));
+    /// assert_eq![Rate::with_tps(40.0).duration(), r.duration()];
+    /// ```
+    pub fn from_std(duration_per_tick: core::time::Duration) -> Self {
+        Self::new(Duration::try_from(duration_per_tick).unwrap_or(Duration::MAX))
+    }
+
     //
 
     /// Resets the number of ticks to 0, and the first and last ticks to now.
@@ -117,6 +250,61 @@ impl Rate {
 
     //
 
+    /// Returns a snapshot of the current progress of the rate.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate, RateSnapshot};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// let t = Instant::now();
+    /// r.set_last_tick(t);
+    /// r.do_tick(t + Duration::milliseconds(10));
+    /// r.do_tick(t + Duration::milliseconds(20));
+    ///
+    /// let snapshot = r.snapshot();
+    /// assert_eq![2, snapshot.ticks];
+    /// assert_eq![t + Duration::milliseconds(20), snapshot.last_tick];
+    ///
+    /// let handle = std::thread::spawn(move || snapshot.ticks);
+    /// assert_eq![2, handle.join().unwrap()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn snapshot(&self) -> RateSnapshot {
+        RateSnapshot {
+            ticks: self.ticks,
+            last_tick: self.last_tick,
+            duration: self.duration,
+        }
+    }
+
+    /// Restores the progress of the rate from a previous `snapshot`.
+    ///
+    /// The [`first_tick`][Self::first_tick] and the accumulated lag are
+    /// not part of the snapshot, and are left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_ticks(42);
+    /// let saved = r.snapshot();
+    ///
+    /// let mut restored = Rate::default();
+    /// restored.restore(saved);
+    /// assert_eq![42, restored.ticks()];
+    /// assert_eq![r.last_tick(), restored.last_tick()];
+    /// assert![r.same_config(&restored)];
+    /// ```
+    #[inline]
+    pub fn restore(&mut self, snapshot: RateSnapshot) {
+        self.ticks = snapshot.ticks;
+        self.last_tick = snapshot.last_tick;
+        self.duration = snapshot.duration;
+    }
+
     /// Returns the current number of ticks.
     ///
     /// # Examples
@@ -126,24 +314,61 @@ impl Rate {
     /// let r = Rate::default();
     /// assert_eq![0, r.ticks()];
     /// ```
+    #[must_use]
     #[inline(always)]
     pub const fn ticks(&self) -> u64 {
         self.ticks
     }
 
-    /// Increments the current number of ticks by 1.
+    /// Returns the id of the current tick, tagged with the marker type `T`.
+    ///
+    /// See [`TickId`] for an example.
+    #[must_use]
+    #[inline]
+    pub const fn current_tick_id<T>(&self) -> TickId<T> {
+        TickId::new(self.ticks)
+    }
+
+    /// Sets the current number of ticks.
     ///
     /// # Examples
     /// ```
     /// use espera::all::Rate;
     ///
     /// let mut r = Rate::default();
+    /// r.set_ticks(1_000);
+    /// assert_eq![1_000, r.ticks()];
+    /// r.set_ticks(0);
+    /// assert_eq![0, r.ticks()];
+    /// r.set_ticks(u64::MAX);
+    /// assert_eq![u64::MAX, r.ticks()];
+    /// ```
+    #[inline(always)]
+    pub fn set_ticks(&mut self, ticks: u64) {
+        self.ticks = ticks;
+    }
+
+    /// Increments the current number of ticks by 1.
+    ///
+    /// It saturates at [`u64::MAX`] instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Instant, Rate};
+    ///
+    /// let mut r = Rate::default();
     /// r.increment_ticks();
     /// assert_eq![1, r.ticks()];
+    ///
+    /// r.set_ticks(u64::MAX - 1);
+    /// r.increment_ticks();
+    /// r.increment_ticks();
+    /// assert![r.do_tick(Instant::now()).is_some()];
+    /// assert_eq![u64::MAX, r.ticks()];
     /// ```
     #[inline(always)]
     pub fn increment_ticks(&mut self) {
-        self.ticks += 1;
+        self.ticks = self.ticks.saturating_add(1);
     }
 
     //
@@ -157,6 +382,7 @@ impl Rate {
     /// let r = Rate::default();
     /// assert![r.first_tick() < Instant::now()];
     /// ```
+    #[must_use]
     #[inline(always)]
     pub const fn first_tick(&self) -> Instant {
         self.first_tick
@@ -187,6 +413,7 @@ impl Rate {
     /// let r = Rate::default();
     /// assert![r.last_tick() >= r.first_tick()];
     /// ```
+    #[must_use]
     #[inline(always)]
     pub const fn last_tick(&self) -> Instant {
         self.last_tick
@@ -219,27 +446,273 @@ impl Rate {
     /// let r = Rate::with_tps(40.0);
     /// assert_eq![Duration::milliseconds(25), r.duration()];
     /// ```
+    #[must_use]
     #[inline(always)]
     pub const fn duration(&self) -> Duration {
         self.duration
     }
 
+    /// Returns the policy when ticking with an instant before the last tick.
+    #[must_use]
+    #[inline(always)]
+    pub const fn on_backwards(&self) -> OnBackwards {
+        self.on_backwards
+    }
+
+    /// Sets the policy when ticking with an instant before the last tick.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, OnBackwards, Rate};
+    ///
+    /// let t = Instant::now();
+    /// let past = t - Duration::seconds(1);
+    /// let mut r = Rate::with_millis(10);
+    ///
+    /// // ignore (the default)
+    /// r.set_last_tick(t);
+    /// assert_eq![None, r.do_tick(past)];
+    /// assert_eq![(0, t), (r.ticks(), r.last_tick())];
+    ///
+    /// // resync
+    /// r.set_on_backwards(OnBackwards::Resync);
+    /// assert_eq![None, r.do_tick(past)];
+    /// assert_eq![(0, past), (r.ticks(), r.last_tick())];
+    /// assert_eq![None, r.do_tick(past + Duration::milliseconds(5))];
+    /// assert![r.do_tick(past + Duration::milliseconds(10)).is_some()];
+    ///
+    /// // treat as zero
+    /// r.set_on_backwards(OnBackwards::TreatAsZero);
+    /// r.set_last_tick(t);
+    /// assert_eq![Some(Duration::ZERO), r.do_tick_fast(past)];
+    /// assert_eq![(2, past), (r.ticks(), r.last_tick())];
+    /// ```
+    #[inline(always)]
+    pub fn set_on_backwards(&mut self, policy: OnBackwards) {
+        self.on_backwards = policy;
+    }
+
+    /// Returns the accumulated lag, which is the sum of the differences
+    /// between each tick's delta and the target duration.
+    ///
+    /// It's used by [`do_tick`][Self::do_tick] to compensate the lag, and
+    /// it's bounded by [`max_lag`][Self::max_lag], which is ± 2.1 s
+    /// ([`DEFAULT_MAX_LAG`][Self::DEFAULT_MAX_LAG]) by default, and can be
+    /// configured with [`set_max_lag`][Self::set_max_lag].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// let mut t = Instant::now();
+    /// r.set_last_tick(t);
+    /// for _ in 0..3 {
+    ///     t += Duration::milliseconds(11);
+    ///     r.do_tick(t);
+    /// }
+    /// assert_eq![Duration::milliseconds(3), r.accumulated_lag()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn accumulated_lag(&self) -> Duration {
+        Duration::nanoseconds(self.delta_rem)
+    }
+
+    /// Returns the maximum accumulated lag, or `None` if it's unlimited.
+    ///
+    /// It's [`DEFAULT_MAX_LAG`][Self::DEFAULT_MAX_LAG] by default.
+    #[must_use]
+    #[inline]
+    pub fn max_lag(&self) -> Option<Duration> {
+        (self.max_lag != i64::MAX).then(|| Duration::nanoseconds(self.max_lag))
+    }
+
+    /// Sets the maximum accumulated lag, either positive or negative,
+    /// or makes it unlimited with `None`.
+    ///
+    /// The current accumulated lag is clamped to the new maximum, and a
+    /// negative `max_lag` is treated as positive.
+    ///
+    /// The bigger the window, the longer the lag that [`do_tick`][Self::do_tick]
+    /// can compensate, which slow rates need in order to keep their schedule.
+    /// On the other hand, after a long stall a fast rate will tick on every
+    /// call until it makes up for the whole window, e.g. up to 126 ticks in a
+    /// row at 60 Hz with the default window of 2.1 s, and many more without a
+    /// limit. Note that the lag and its maximum are stored in 64 bits each,
+    /// in order to support any window, which makes a `Rate` take 80 bytes
+    /// instead of 64.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// // a rate of 10 seconds per tick, where a tick arrives 5 seconds late
+    /// let mut r = Rate::with_seconds(10.);
+    /// r.set_max_lag(Some(Duration::seconds(10)));
+    /// let t = Instant::now();
+    /// r.set_last_tick(t);
+    /// assert![r.do_tick(t + Duration::seconds(15)).is_some()];
+    /// assert_eq![Duration::seconds(5), r.accumulated_lag()];
+    ///
+    /// // the lag is compensated, so the next tick keeps the original schedule
+    /// let t = t + Duration::seconds(15);
+    /// assert_eq![None, r.do_tick(t + Duration::milliseconds(4_999))];
+    /// assert![r.do_tick(t + Duration::seconds(5)).is_some()];
+    /// assert_eq![Duration::ZERO, r.accumulated_lag()];
+    ///
+    /// // with the default window the lag can't be fully compensated
+    /// let mut r = Rate::with_seconds(10.);
+    /// assert_eq![Some(Rate::DEFAULT_MAX_LAG), r.max_lag()];
+    /// let t = Instant::now();
+    /// r.set_last_tick(t);
+    /// assert![r.do_tick(t + Duration::seconds(15)).is_some()];
+    /// assert_eq![Rate::DEFAULT_MAX_LAG, r.accumulated_lag()];
+    ///
+    /// r.set_max_lag(None);
+    /// assert_eq![None, r.max_lag()];
+    /// ```
+    #[inline]
+    pub fn set_max_lag(&mut self, max_lag: Option<Duration>) {
+        self.max_lag = max_lag.map_or(i64::MAX, |max| {
+            max.whole_nanoseconds()
+                .unsigned_abs()
+                .min(i64::MAX as u128 - 1) as i64
+        });
+        self.delta_rem = self.delta_rem.clamp(-self.max_lag, self.max_lag);
+    }
+
+    /// Resets the accumulated lag to zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// let t = Instant::now();
+    /// r.set_last_tick(t);
+    /// r.do_tick(t + Duration::milliseconds(12));
+    /// r.reset_lag();
+    /// assert_eq![Duration::ZERO, r.accumulated_lag()];
+    /// ```
+    #[inline]
+    pub fn reset_lag(&mut self) {
+        self.delta_rem = 0;
+    }
+
+    /// Compares two rates by their duration per tick, ignoring any other field.
+    ///
+    /// A faster rate (with a shorter duration) is ordered before a slower one.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let (a, mut b) = (Rate::with_millis(10), Rate::with_millis(10));
+    /// b.set_first_tick(Instant::now() + Duration::SECOND);
+    /// assert_eq![Ordering::Equal, a.cmp_by_duration(&b)];
+    /// assert_eq![Ordering::Less, a.cmp_by_duration(&Rate::with_millis(20))];
+    ///
+    /// let mut rates = vec![Rate::with_tps(30.), Rate::with_tps(120.), Rate::with_tps(60.)];
+    /// rates.sort_by(Rate::cmp_by_duration);
+    /// assert_eq![120., rates[0].tps().round()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn cmp_by_duration(&self, other: &Rate) -> core::cmp::Ordering {
+        self.duration.cmp(&other.duration)
+    }
+
+    /// Returns `true` if both rates have the same configuration, i.e. the same
+    /// [`duration`][Self::duration], [`max_lag`][Self::max_lag] and
+    /// [`on_backwards`][Self::on_backwards] policy, ignoring the runtime
+    /// state like the ticks and their instants.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{OnBackwards, Rate};
+    ///
+    /// let (a, mut b) = (Rate::with_tps(60.0), Rate::with_tps(60.0));
+    /// assert![a.same_config(&b)];
+    /// b.do_tick_now();
+    /// assert![a.same_config(&b)];
+    ///
+    /// b.set_on_backwards(OnBackwards::Resync);
+    /// assert![!a.same_config(&b)];
+    /// assert![!a.same_config(&Rate::with_tps(30.0))];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn same_config(&self, other: &Rate) -> bool {
+        self.duration == other.duration
+            && self.max_lag == other.max_lag
+            && self.on_backwards == other.on_backwards
+    }
+
     /// Returns the ticks per second.
     ///
+    /// Returns `0.0` if the duration is not positive, like in the default rate.
+    ///
     /// # Examples
     /// ```
     /// use espera::all::{Duration, Rate};
     ///
     /// let r = Rate::new(Duration::milliseconds(25));
     /// assert_eq![40.0, r.tps()];
+    /// assert_eq![0.0, Rate::default().tps()];
     /// ```
+    #[must_use]
     #[inline(always)]
     pub fn tps(&self) -> f64 {
-        1. / self.duration.as_seconds_f64()
+        if self.duration.is_positive() {
+            1. / self.duration.as_seconds_f64()
+        } else {
+            0.
+        }
+    }
+
+    /// Returns the observed ticks per second, as the number of ticks divided
+    /// by the time elapsed since the [`first_tick`][Self::first_tick] until
+    /// `now`.
+    ///
+    /// Unlike [`tps`][Self::tps], which returns the target rate, this returns
+    /// the achieved rate. Returns `0.0` if no time has elapsed.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_tps(100.);
+    /// r.set_first_tick(start);
+    /// r.set_last_tick(start);
+    /// assert_eq![0., r.observed_tps(start)];
+    ///
+    /// // ticks every 20 ms, slower than the target of 10 ms
+    /// for i in 1..=50 {
+    ///     r.do_tick(start + Duration::milliseconds(i * 20));
+    /// }
+    /// let now = start + Duration::SECOND;
+    /// assert![(r.observed_tps(now) - 50.).abs() < 0.001];
+    /// assert![r.observed_tps(now) < r.tps()];
+    /// ```
+    #[must_use]
+    pub fn observed_tps(&self, now: Instant) -> f64 {
+        let elapsed = self.first_elapsed(now);
+        if elapsed.is_positive() {
+            self.ticks as f64 / elapsed.as_seconds_f64()
+        } else {
+            0.
+        }
     }
 
     /// Sets the `duration_per_tick`.
     ///
+    /// Nothing else is changed, so the instants of the already elapsed ticks
+    /// get recalculated with the new duration. See [`rescale`][Self::rescale]
+    /// for changing the duration while preserving the phase.
+    ///
     /// # Examples
     /// ```
     /// use espera::all::{Duration, Rate};
@@ -252,6 +725,34 @@ impl Rate {
         self.duration = duration_per_tick;
     }
 
+    /// Sets the `duration_per_tick` while preserving the phase.
+    ///
+    /// The [`first_tick`][Self::first_tick] is recomputed so that the instant
+    /// of the current tick stays the same, and the following ticks are
+    /// scheduled from there with the new duration, avoiding a jump.
+    ///
+    /// # Panics
+    /// Panics on overflow, like [`instant_tick`][Self::instant_tick].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_first_tick(Instant::now());
+    /// r.set_ticks(100);
+    ///
+    /// let current = r.instant_tick(r.ticks());
+    /// r.rescale(Duration::milliseconds(4));
+    /// assert_eq![current, r.instant_tick(r.ticks())];
+    /// assert_eq![current + Duration::milliseconds(4), r.instant_tick(101)];
+    /// ```
+    pub fn rescale(&mut self, new_duration: Duration) {
+        let current = self.instant_tick(self.ticks);
+        self.duration = new_duration;
+        self.first_tick = current - self.duration_ticks(self.ticks);
+    }
+
     /// Sets the `seconds_per_tick`.
     ///
     /// # Examples
@@ -268,6 +769,11 @@ impl Rate {
 
     /// Sets the `ticks_per_second`.
     ///
+    /// See [`try_set_tps`][Self::try_set_tps] for a non-panicking version.
+    ///
+    /// # Panics
+    /// Panics in the same cases as [`with_tps`][Self::with_tps].
+    ///
     /// # Examples
     /// ```
     /// use espera::all::Rate;
@@ -280,6 +786,33 @@ impl Rate {
         self.duration = Duration::seconds_f64(1. / ticks_per_second);
     }
 
+    /// Tries to set the `ticks_per_second`.
+    ///
+    /// The rate is left unchanged on error.
+    ///
+    /// # Errors
+    /// Returns [`EsperaError::OutOfBounds`] in the same cases as
+    /// [`try_with_tps`][Self::try_with_tps].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Rate};
+    ///
+    /// let mut r = Rate::with_tps(40.0);
+    /// assert![r.try_set_tps(f64::NAN).is_err()];
+    /// assert![r.try_set_tps(0.0).is_err()];
+    /// assert![r.try_set_tps(-1.0).is_err()];
+    /// assert_eq![Duration::milliseconds(25), r.duration()];
+    ///
+    /// r.try_set_tps(50.0).unwrap();
+    /// assert_eq![Duration::milliseconds(20), r.duration()];
+    /// ```
+    #[inline]
+    pub fn try_set_tps(&mut self, ticks_per_second: f64) -> EsperaResult<()> {
+        self.duration = duration_from_tps(ticks_per_second)?;
+        Ok(())
+    }
+
     //
 
     /// Returns the duration between the [`last_tick`][Self::last_tick] and
@@ -288,14 +821,18 @@ impl Rate {
     /// In which case the [`ticks`][Self::ticks] counter is incremented
     /// and the instant of the last tick is replaced with the given `instant`.
     ///
-    /// Otherwise, if the time difference is negative `None` is returned.
+    /// Otherwise, if the time difference is negative, the
+    /// [`on_backwards`][Self::on_backwards] policy is applied,
+    /// which by default returns `None`.
     ///
     /// # Precision
     /// This version should give a much more precise average frame rate than
     /// [`do_tick_fast`][Self::do_tick_fast], because it takes into account the
     /// accumulated lag, at the cost of being a little less performant.
     ///
-    /// The maximum accumulated lag is ± 2.1 s (±[`i32::MAX`] ns).
+    /// The maximum accumulated lag is ± 2.1 s (±[`i32::MAX`] ns) by default,
+    /// and can be configured with [`set_max_lag`][Self::set_max_lag].
+    ///
     /// # Examples
     /// ```
     /// use espera::all::{Instant, Rate};
@@ -305,53 +842,132 @@ impl Rate {
     ///     let d = r.do_tick(Instant::now());
     /// }
     /// ```
+    #[must_use = "the returned delta tells whether the rate ticked"]
     #[inline]
     pub fn do_tick(&mut self, instant: Instant) -> Option<Duration> {
         let delta = self.last_elapsed(instant);
-        if (delta + Duration::new(0, self.delta_rem)) >= self.duration {
-            let lag: i128 = (delta - self.duration).whole_nanoseconds();
-            let lag_clamped = lag.clamp(i32::MIN as i128, i32::MAX as i128);
-
-            // FIX overflows!
-            // log::debug![
-            //     ">> Δ:{delta} + Δ_acc:{} ...",
-            //     self.delta_rem
-            // ];
-            // self.delta_rem += lag_clamped as i32;
-            self.delta_rem = self.delta_rem.saturating_add(lag_clamped as i32);
-
-            //     ">> Δ:{delta} + Δ_acc:{} >= dur (lag:{lag_clamped})",
-            //     self.delta_rem
-            // ];
-            self.increment_ticks();
-            self.set_last_tick(instant);
+        if delta.is_negative() {
+            self.tick_backwards(instant)
+        } else if (delta + Duration::nanoseconds(self.delta_rem)) >= self.duration {
+            self.tick_forward(delta, instant);
             Some(delta)
         } else {
             None
         }
     }
+
     /// Calls [`do_tick`][Self::do_tick] with `Instant::now()`.
+    #[must_use = "the returned delta tells whether the rate ticked"]
     #[inline(always)]
     pub fn do_tick_now(&mut self) -> Option<Duration> {
         self.do_tick(Instant::now())
     }
 
+    /// Advances the rate by one tick, with the given `delta` since the last
+    /// tick, without needing a real [`Instant`].
+    ///
+    /// The instant of the last tick is moved forward by `delta`, the ticks
+    /// counter is incremented, and the accumulated lag is updated, exactly as
+    /// [`do_tick`][Self::do_tick] does when it ticks.
+    ///
+    /// This allows replaying a recorded sequence of deltas deterministically.
+    ///
+    /// Returns `false` and does nothing if `delta` is negative, or if the new
+    /// instant of the last tick would overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let recorded = [16_900, 16_500, 17_100, 16_600, 33_400].map(Duration::microseconds);
+    /// let start = Instant::now();
+    ///
+    /// let mut live = Rate::with_micros(16_667);
+    /// live.set_first_tick(start);
+    /// live.set_last_tick(start);
+    /// let mut replay = live.clone();
+    ///
+    /// let mut instant = start;
+    /// for delta in recorded {
+    ///     instant += delta;
+    ///     assert_eq![Some(delta), live.do_tick(instant)];
+    ///     assert![replay.advance_by(delta)];
+    /// }
+    /// assert_eq![live.ticks(), replay.ticks()];
+    /// assert_eq![live.last_tick(), replay.last_tick()];
+    /// assert_eq![live.accumulated_lag(), replay.accumulated_lag()];
+    /// assert_eq![live, replay];
+    ///
+    /// assert![!replay.advance_by(Duration::milliseconds(-1))];
+    /// assert![!replay.advance_by(Duration::MAX)];
+    /// assert_eq![live, replay];
+    /// ```
+    pub fn advance_by(&mut self, delta: Duration) -> bool {
+        if delta.is_negative() {
+            return false;
+        }
+        if let Some(instant) = self.last_tick.checked_add(delta) {
+            self.tick_forward(delta, instant);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Like [`do_tick`][Self::do_tick], but also returns the signed pacing
+    /// error of the tick, as `now` minus the instant the tick was scheduled
+    /// for, according to [`instant_tick`][Self::instant_tick].
+    ///
+    /// Returns `Some((delta, error))` if the rate ticked, where a positive
+    /// error means the tick is late, and a negative one means it's early.
+    ///
+    /// The scheduled instant saturates like in
+    /// [`instant_tick_saturating`][Self::instant_tick_saturating].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_first_tick(start);
+    /// r.set_last_tick(start);
+    ///
+    /// let ms = Duration::milliseconds;
+    /// assert_eq![Some((ms(10), ms(0))), r.do_tick_timed(start + ms(10))];
+    /// // a late tick
+    /// assert_eq![Some((ms(13), ms(3))), r.do_tick_timed(start + ms(23))];
+    /// // the lag compensation allows an early tick
+    /// assert_eq![Some((ms(8), ms(1))), r.do_tick_timed(start + ms(31))];
+    /// assert_eq![None, r.do_tick_timed(start + ms(32))];
+    /// ```
+    #[must_use = "the returned delta tells whether the rate ticked"]
+    pub fn do_tick_timed(&mut self, now: Instant) -> Option<(Duration, Duration)> {
+        let scheduled = self.instant_tick_saturating(self.ticks.saturating_add(1));
+        self.do_tick(now).map(|delta| (delta, now - scheduled))
+    }
+
     /// Returns the duration between the last tick and the provided `instant`,
     /// as long as the duration is non-negative.
     ///
     /// If the duration is non-negative, the ticks counter is incremented
     /// and the instant of the last tick is replaced with the given `instant`.
     ///
-    /// Otherwise, if the time difference is negative then `None` is returned.
+    /// Otherwise, if the time difference is negative, the
+    /// [`on_backwards`][Self::on_backwards] policy is applied,
+    /// which by default returns `None`.
     ///
     /// # Precision
     /// This version is less precise than [`do_tick`][Self::do_tick],
     /// because it doesn't try to compensate accumulated lag. It will probably
     /// lag a little behind the target rate, but should also be a little faster.
+    #[must_use = "the returned delta tells whether the rate ticked"]
     #[inline]
     pub fn do_tick_fast(&mut self, instant: Instant) -> Option<Duration> {
         let delta = self.last_elapsed(instant);
-        if delta >= self.duration {
+        if delta.is_negative() {
+            self.tick_backwards(instant)
+        } else if delta >= self.duration {
             self.increment_ticks();
             self.set_last_tick(instant);
             Some(delta)
@@ -360,15 +976,95 @@ impl Rate {
         }
     }
     /// Calls [`do_tick_fast`][Self::do_tick_fast] with `Instant::now()`.
+    #[must_use = "the returned delta tells whether the rate ticked"]
     #[inline(always)]
     pub fn do_tick_fast_now(&mut self) -> Option<Duration> {
         self.do_tick_fast(Instant::now())
     }
 
+    /// Like [`do_tick_fast`][Self::do_tick_fast], but also returns the number
+    /// of whole tick periods that were skipped since the last tick.
+    ///
+    /// Only a single tick is counted, no matter how many periods have elapsed,
+    /// allowing a fast producer to drive a slower rate by dropping the
+    /// intermediate ticks.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::new(Duration::milliseconds(10));
+    /// let t = Instant::now();
+    /// r.set_last_tick(t);
+    ///
+    /// assert_eq![None, r.do_tick_coalesced(t + Duration::milliseconds(5))];
+    /// assert_eq![
+    ///     Some((Duration::milliseconds(15), 0)),
+    ///     r.do_tick_coalesced(t + Duration::milliseconds(15))
+    /// ];
+    /// assert_eq![
+    ///     Some((Duration::milliseconds(20), 1)),
+    ///     r.do_tick_coalesced(t + Duration::milliseconds(35))
+    /// ];
+    /// assert_eq![
+    ///     Some((Duration::milliseconds(40), 3)),
+    ///     r.do_tick_coalesced(t + Duration::milliseconds(75))
+    /// ];
+    /// assert_eq![3, r.ticks()];
+    /// ```
+    #[must_use = "the returned delta tells whether the rate ticked"]
+    #[inline]
+    pub fn do_tick_coalesced(&mut self, instant: Instant) -> Option<(Duration, u64)> {
+        let delta = self.last_elapsed(instant);
+        if delta.is_negative() {
+            self.tick_backwards(instant).map(|delta| (delta, 0))
+        } else if delta >= self.duration {
+            let skipped = self.owed_ticks(instant).saturating_sub(1);
+            self.increment_ticks();
+            self.set_last_tick(instant);
+            Some((delta, skipped))
+        } else {
+            None
+        }
+    }
+    /// Calls [`do_tick_coalesced`][Self::do_tick_coalesced] with `Instant::now()`.
+    #[must_use = "the returned delta tells whether the rate ticked"]
+    #[inline(always)]
+    pub fn do_tick_coalesced_now(&mut self) -> Option<(Duration, u64)> {
+        self.do_tick_coalesced(Instant::now())
+    }
+
+    // Registers a tick at the given `instant`, `delta` after the last tick,
+    // accumulating its lag.
+    fn tick_forward(&mut self, delta: Duration, instant: Instant) {
+        let lag: i128 = (delta - self.duration).whole_nanoseconds();
+        let max_lag = self.max_lag as i128;
+        self.delta_rem = (self.delta_rem as i128 + lag).clamp(-max_lag, max_lag) as i64;
+        self.increment_ticks();
+        self.set_last_tick(instant);
+    }
+
+    // Applies the `on_backwards` policy for an `instant` before the last tick.
+    fn tick_backwards(&mut self, instant: Instant) -> Option<Duration> {
+        match self.on_backwards {
+            OnBackwards::Ignore => None,
+            OnBackwards::Resync => {
+                self.set_last_tick(instant);
+                None
+            }
+            OnBackwards::TreatAsZero => {
+                self.increment_ticks();
+                self.set_last_tick(instant);
+                Some(Duration::ZERO)
+            }
+        }
+    }
+
     //
 
     /// Returns the elapsed time between the given `instant` and the first
     /// recorded tick.
+    #[must_use]
     #[inline(always)]
     pub fn first_elapsed(&self, instant: Instant) -> Duration {
         instant - self.first_tick
@@ -376,26 +1072,252 @@ impl Rate {
 
     /// Returns the elapsed time between the given `instant` and the last
     /// recorded tick.
+    #[must_use]
     #[inline(always)]
     pub fn last_elapsed(&self, instant: Instant) -> Duration {
         instant - self.last_tick
     }
 
-    /// Returns the elapsed time between the given `instant` and `tick` number.
+    /// Returns the time left from `now` until the rate is due to tick again,
+    /// which is negative if the tick is overdue.
+    ///
+    /// It's calculated as the [`duration`][Self::duration] minus the
+    /// [`last_elapsed`][Self::last_elapsed] time, without taking into account
+    /// the accumulated lag.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let start = Instant::now();
+    /// let mut r = Rate::with_millis(10);
+    /// r.set_last_tick(start);
+    ///
+    /// let ms = Duration::milliseconds;
+    /// assert_eq![ms(10), r.time_to_next(start)];
+    /// assert_eq![ms(6), r.time_to_next(start + ms(4))];
+    /// assert_eq![ms(0), r.time_to_next(start + ms(10))];
+    /// assert_eq![ms(-5), r.time_to_next(start + ms(15))];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn time_to_next(&self, now: Instant) -> Duration {
+        self.duration - self.last_elapsed(now)
+    }
+
+    /// Returns the elapsed time between the given `instant` and `tick` number,
     /// according to the [`duration`][Self::duration] per tick and the
     /// [`first_tick`][Self::first_tick].
     ///
-    /// If the `tick` is in the future the duration will be negative.
-    /// or the negative duration of when it will come to pass.
-    // TEST
+    /// If the `tick` is in the future the duration will be negative,
+    /// representing the time left until it comes to pass.
+    ///
+    /// # Panics
+    /// Panics if the instant of the `tick` overflows, like in
+    /// [`instant_tick`][Self::instant_tick].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// let first = Instant::now();
+    /// r.set_first_tick(first);
+    ///
+    /// let now = first + Duration::milliseconds(25);
+    /// assert_eq![Duration::milliseconds(25), r.tick_elapsed(0, now)];
+    /// assert_eq![Duration::milliseconds(5), r.tick_elapsed(2, now)];
+    /// // a future tick
+    /// assert_eq![Duration::milliseconds(-15), r.tick_elapsed(4, now)];
+    /// ```
+    #[must_use]
     #[inline(always)]
-    pub fn tick_elapsed(&self, tick: u64, instant: Instant) -> Duration {
+    pub fn tick_elapsed(&self, tick: impl TickIndex, instant: Instant) -> Duration {
         instant - self.instant_tick(tick)
     }
 
+    /// Returns the elapsed time between the given `instant` and `tick` number,
+    /// according to the [`duration`][Self::duration] per tick and the
+    /// [`first_tick`][Self::first_tick].
+    ///
+    /// Like [`tick_elapsed`][Self::tick_elapsed] but returns `None` instead
+    /// of panicking if the instant of the `tick` overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// let first = Instant::now();
+    /// r.set_first_tick(first);
+    ///
+    /// let now = first + Duration::milliseconds(25);
+    /// assert_eq![Some(Duration::milliseconds(-15)), r.tick_elapsed_checked(4, now)];
+    ///
+    /// let r = Rate::with_seconds(1e9);
+    /// assert_eq![None, r.tick_elapsed_checked(u64::MAX, now)];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn tick_elapsed_checked(&self, tick: impl TickIndex, instant: Instant) -> Option<Duration> {
+        self.instant_tick_checked(tick).map(|tick| instant - tick)
+    }
+
+    /// Returns the number of whole tick periods elapsed between the
+    /// [`last_tick`][Self::last_tick] and the given `instant`,
+    /// without modifying the rate.
+    ///
+    /// Returns `0` if the [`duration`][Self::duration] is not positive,
+    /// or if the `instant` is previous to the last tick.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::new(Duration::milliseconds(10));
+    /// let last = Instant::now();
+    /// r.set_last_tick(last);
+    /// assert_eq![0, r.owed_ticks(last + Duration::nanoseconds(9_999_999))];
+    /// assert_eq![1, r.owed_ticks(last + Duration::milliseconds(10))];
+    /// assert_eq![25, r.owed_ticks(last + Duration::milliseconds(255))];
+    /// assert_eq![0, r.owed_ticks(last - Duration::milliseconds(20))];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn owed_ticks(&self, instant: Instant) -> u64 {
+        let period = self.duration.whole_nanoseconds();
+        let elapsed = self.last_elapsed(instant).whole_nanoseconds();
+        if period <= 0 || elapsed <= 0 {
+            0
+        } else {
+            (elapsed / period).min(u64::MAX as i128) as u64
+        }
+    }
+
+    /// Returns the estimated time remaining until reaching `total_ticks`,
+    /// at the `now` instant.
+    ///
+    /// The estimate is based on the observed average duration per tick,
+    /// measured from the [`first_tick`][Self::first_tick] until `now`,
+    /// multiplied by the number of remaining ticks.
+    ///
+    /// Returns `None` before the first tick, or if the estimate is not
+    /// representable.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(10);
+    /// let start = Instant::now();
+    /// r.set_first_tick(start);
+    /// r.set_last_tick(start);
+    ///
+    /// // 0%
+    /// assert_eq![None, r.eta(100, start)];
+    ///
+    /// // 50%, at a slower pace than the target: 20ms per tick
+    /// for t in 1..=50 {
+    ///     r.do_tick_fast(start + Duration::milliseconds(t * 20));
+    /// }
+    /// let now = start + Duration::seconds(1);
+    /// assert_eq![Some(Duration::seconds(1)), r.eta(100, now)];
+    ///
+    /// // near completion
+    /// for t in 51..=99 {
+    ///     r.do_tick_fast(start + Duration::milliseconds(t * 20));
+    /// }
+    /// let now = start + Duration::milliseconds(1980);
+    /// assert_eq![Some(Duration::milliseconds(20)), r.eta(100, now)];
+    /// assert_eq![Some(Duration::ZERO), r.eta(99, now)];
+    /// ```
+    #[must_use]
+    pub fn eta(&self, total_ticks: u64, now: Instant) -> Option<Duration> {
+        if self.ticks == 0 {
+            return None;
+        }
+        let elapsed = self.first_elapsed(now).whole_nanoseconds().max(0);
+        let remaining = total_ticks.saturating_sub(self.ticks) as i128;
+        let eta = elapsed.checked_mul(remaining)? / self.ticks as i128;
+        let seconds = i64::try_from(eta / 1_000_000_000).ok()?;
+        Some(Duration::new(seconds, (eta % 1_000_000_000) as i32))
+    }
+
+    /// Returns the phase of the given `instant` within the tick period,
+    /// in the range `0.0..1.0`.
+    ///
+    /// The phase is anchored at the [`first_tick`][Self::first_tick],
+    /// so it's continuous regardless of when the ticks actually happened.
+    ///
+    /// Returns `0.0` if the [`duration`][Self::duration] is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::new(Duration::milliseconds(100));
+    /// let anchor = Instant::now();
+    /// r.set_first_tick(anchor);
+    /// assert_eq![0.0, r.phase(anchor)];
+    /// assert_eq![0.25, r.phase(anchor + Duration::milliseconds(25))];
+    /// assert_eq![0.0, r.phase(anchor + Duration::milliseconds(100))];
+    /// assert_eq![0.5, r.phase(anchor + Duration::milliseconds(150))];
+    /// assert_eq![0.75, r.phase(anchor - Duration::milliseconds(25))];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn phase(&self, instant: Instant) -> f64 {
+        let period = self.duration.whole_nanoseconds();
+        if period <= 0 {
+            return 0.0;
+        }
+        let elapsed = self.first_elapsed(instant).whole_nanoseconds();
+        elapsed.rem_euclid(period) as f64 / period as f64
+    }
+
+    /// Returns an iterator over the indices of the ticks after the current
+    /// one, whose scheduled instant is at or before the `deadline`.
+    ///
+    /// The scheduled instants are calculated like in
+    /// [`instant_tick`][Self::instant_tick], but without floating point.
+    /// It doesn't sleep nor modify the rate.
+    ///
+    /// Yields nothing if the [`duration`][Self::duration] is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let mut r = Rate::with_millis(100);
+    /// let start = Instant::now();
+    /// r.set_first_tick(start);
+    /// let deadline = start + Duration::seconds(1);
+    /// assert_eq![(1..=10).collect::<Vec<_>>(), r.ticks_until(deadline).collect::<Vec<_>>()];
+    ///
+    /// r.set_ticks(7);
+    /// assert_eq![vec![8, 9, 10], r.ticks_until(deadline).collect::<Vec<_>>()];
+    ///
+    /// assert_eq![0, r.ticks_until(start - Duration::seconds(1)).count()];
+    /// assert_eq![0, Rate::default().ticks_until(deadline).count()];
+    /// ```
+    pub fn ticks_until(&self, deadline: Instant) -> impl Iterator<Item = u64> {
+        let period = self.duration.whole_nanoseconds();
+        let elapsed = self.first_elapsed(deadline).whole_nanoseconds();
+        let last = if period <= 0 || elapsed < 0 || self.ticks == u64::MAX {
+            0
+        } else {
+            (elapsed / period).min(u64::MAX as i128) as u64
+        };
+        self.ticks.saturating_add(1)..=last
+    }
+
     /// Returns the total duration for the provided number of `ticks`.
     ///
     /// Note that the maximum representable duration is i64::MAX seconds (+2.14).
+    ///
+    /// # Panics
+    /// Panics if the total duration is not representable.
+    #[must_use]
     #[inline(always)]
     pub fn duration_ticks(&self, ticks: u64) -> Duration {
         Duration::seconds_f64(self.duration.as_seconds_f64() * ticks as f64)
@@ -409,9 +1331,10 @@ impl Rate {
     /// Panics on overflow which, for example, in linux can happen at
     /// [`u64::MAX`] ticks at 500ms per-tick, [`i64::MAX`] ticks at 1s per-tick,
     /// or [`u32::MAX`] ticks at ([`i32::MAX`] + 2)s per-tick...
+    #[must_use]
     #[inline(always)]
-    pub fn instant_tick(&self, tick: u64) -> Instant {
-        self.first_tick + self.duration_ticks(tick)
+    pub fn instant_tick(&self, tick: impl TickIndex) -> Instant {
+        self.first_tick + self.duration_ticks(tick.tick_index())
     }
 
     /// Returns the instant the provided `tick` should happen, according to the
@@ -420,30 +1343,186 @@ impl Rate {
     ///
     /// Like [`instant_tick`][Self::instant_tick] but returns `None` instead of
     /// panicking.
+    #[must_use]
     #[inline(always)]
-    pub fn instant_tick_checked(&self, tick: u64) -> Option<Instant> {
-        self.first_tick.checked_add(self.duration_ticks(tick))
+    pub fn instant_tick_checked(&self, tick: impl TickIndex) -> Option<Instant> {
+        let ticks = tick.tick_index() as f64;
+        let duration = Duration::checked_seconds_f64(self.duration.as_seconds_f64() * ticks)?;
+        self.first_tick.checked_add(duration)
     }
 
-    //
+    /// Returns the instant the provided `tick` should happen, according to the
+    /// [`duration`][Self::duration] per tick, and the instant of the
+    /// [`first_tick`][Self::first_tick].
+    ///
+    /// Like [`instant_tick`][Self::instant_tick] but instead of panicking on
+    /// overflow it returns the furthest instant representable by the platform
+    /// in the direction of the tick.
+    ///
+    /// The limits are the ones described in [`instant_tick`][Self::instant_tick],
+    /// and finding the clamped instant on overflow involves a binary search.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, Rate};
+    ///
+    /// let r = Rate::with_millis(500);
+    /// assert_eq![r.instant_tick(10), r.instant_tick_saturating(10)];
+    ///
+    /// assert![r.instant_tick_checked(u64::MAX).is_none()];
+    /// let max = r.instant_tick_saturating(u64::MAX);
+    /// assert![max > r.instant_tick(1_000_000)];
+    /// assert![max.checked_add(Duration::NANOSECOND).is_none()];
+    /// assert_eq![max, r.instant_tick_saturating(u64::MAX - 1)];
+    /// ```
+    #[must_use]
+    pub fn instant_tick_saturating(&self, tick: impl TickIndex) -> Instant {
+        let ticks = tick.tick_index() as f64;
+        let total = Duration::saturating_seconds_f64(self.duration.as_seconds_f64() * ticks);
+        if let Some(instant) = self.first_tick.checked_add(total) {
+            return instant;
+        }
+        // the `low` offset is always representable, and the `high` one never is
+        let (mut low, mut high) = (Duration::ZERO, total);
+        while (high - low).abs() > Duration::NANOSECOND {
+            let mid = low + (high - low) / 2;
+            if self.first_tick.checked_add(mid).is_some() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        self.first_tick + low
+    }
 
-    // MAYBE
-    // /// Pretty print...?
-    // pub fn to_string_with_delta(&self, delta: Duration) -> String {
     //
-    // }
+
+    /// Writes the [`Display`][core::fmt::Display] representation of the rate
+    /// into the given writer, without allocating.
+    ///
+    /// This allows reusing a buffer in hot logging paths.
+    ///
+    /// # Errors
+    /// Returns an error if the writer fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Rate;
+    ///
+    /// let mut r = Rate::with_millis(25);
+    /// let mut buf = String::with_capacity(64);
+    /// r.write_to(&mut buf).unwrap();
+    /// assert_eq![r.to_string(), buf];
+    ///
+    /// let capacity = buf.capacity();
+    /// for _ in 0..1000 {
+    ///     r.increment_ticks();
+    ///     buf.clear();
+    ///     r.write_to(&mut buf).unwrap();
+    /// }
+    /// assert![buf.starts_with("#01000,")];
+    /// assert_eq![capacity, buf.capacity()];
+    /// ```
+    #[inline]
+    pub fn write_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        write![w, "{self}"]
+    }
+
+    /// Returns a human readable line with the ticks, the duration per tick
+    /// and the accumulated lag, formatted with [`timecode_ns_u64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Rate;
+    ///
+    /// let r = Rate::with_millis(25);
+    /// let s = r.to_string_pretty();
+    /// assert_eq!["#00000, dpt:25ms 000µs 000000ns, lag:000000ns", s];
+    /// ```
+    #[must_use]
+    pub fn to_string_pretty(&self) -> String {
+        format![
+            "#{:05}, dpt:{}, lag:{}",
+            self.ticks,
+            timecode_ns_i128(self.duration.whole_nanoseconds()),
+            timecode_ns_i128(self.delta_rem.into()),
+        ]
+    }
+
+    /// Returns a human readable line like [`to_string_pretty`][Self::to_string_pretty],
+    /// followed by the given `delta` and its equivalent ticks per second.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Rate};
+    ///
+    /// let r = Rate::with_millis(25);
+    /// let s = r.to_string_with_delta(Duration::microseconds(20_500));
+    /// assert![s.ends_with(", Δ:20ms 500µs 000000ns, tps:48.78")];
+    /// ```
+    #[must_use]
+    pub fn to_string_with_delta(&self, delta: Duration) -> String {
+        let tps = if delta.is_positive() {
+            1. / delta.as_seconds_f64()
+        } else {
+            0.
+        };
+        format![
+            "{}, Δ:{}, tps:{tps:.2}",
+            self.to_string_pretty(),
+            timecode_ns_i128(delta.whole_nanoseconds()),
+        ]
+    }
+}
+
+// Returns the duration per tick of the given `ticks_per_second`,
+// which must be finite and positive.
+fn duration_from_tps(ticks_per_second: f64) -> EsperaResult<Duration> {
+    if !(ticks_per_second.is_finite() && ticks_per_second > 0.) {
+        return Err(EsperaError::OutOfBounds(
+            "The ticks per second must be finite and positive.",
+        ));
+    }
+    Duration::checked_seconds_f64(1. / ticks_per_second).ok_or(EsperaError::OutOfBounds(
+        "The duration per tick is not representable.",
+    ))
+}
+
+// Returns the signed time code of the given nanoseconds.
+//
+// The magnitude saturates at `u64::MAX` nanoseconds.
+fn timecode_ns_i128(ns: i128) -> String {
+    let abs = ns.unsigned_abs().min(u64::MAX as u128) as u64;
+    if ns < 0 {
+        format!["-{}", timecode_ns_u64(abs)]
+    } else {
+        timecode_ns_u64(abs)
+    }
 }
 
 mod core_impl {
     use super::Rate;
     use core::fmt;
 
+    /// Shows the ticks, the ticks per second, the duration per tick,
+    /// and the accumulated lag in nanoseconds.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::{all::Rate, time::FRAME_60HZ};
+    ///
+    /// assert_eq!["#00000, tps:40.00, dpt:25ms, Δdiff:0", Rate::with_millis(25).to_string()];
+    /// assert_eq!["#00000, tps:60.00, dpt:16ms666µs667ns, Δdiff:0", Rate::new(FRAME_60HZ).to_string()];
+    /// ```
     impl fmt::Display for Rate {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             write!(
                 f,
-                "#{:05}, tps:{}, Δdiff:{}",
-                self.ticks, self.duration, self.delta_rem,
+                "#{:05}, tps:{:.2}, dpt:{}, Δdiff:{}",
+                self.ticks,
+                self.tps(),
+                self.duration,
+                self.delta_rem,
             )
         }
     }
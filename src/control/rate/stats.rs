@@ -1,11 +1,12 @@
 // espera::rate::stats
 //
-//!
+//! Statistics of rates.
 //
 
 use crate::all::{Duration, Rate};
 use arraydeque::{ArrayDeque, Wrapping};
 use core::cmp;
+use std::collections::BTreeMap;
 
 // /// The max size of the ring buffer that stores measures.
 // const RATE_RING_LEN: usize = 1024;
@@ -18,6 +19,21 @@ const NS_TO_S: f64 = 1e-9;
 // const S_TO_MS: f64 = 1e+3;
 // const MS_TO_S: f64 = 1e-3;
 
+/// The statistics of a single time window of a [`RateStats`].
+///
+/// It's returned by [`RateStats::report`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowStats {
+    /// The average nanoseconds.
+    pub avg_ns: f64,
+    /// The maximum nanoseconds.
+    pub max_ns: u64,
+    /// The average frames per second.
+    pub avg_fps: f64,
+    /// The minimum frames per second.
+    pub min_fps: f64,
+}
+
 /// The statistics of a given [`Rate`].
 ///
 /// Average
@@ -34,6 +50,9 @@ pub struct RateStats {
     max_ns_16: u64,
     max_ns_128: u64,
     max_ns_1024: u64,
+
+    /// The optional `(min, max)` nanoseconds every sample is clamped to.
+    clamp: Option<(u64, u64)>,
 }
 
 impl Default for RateStats {
@@ -47,6 +66,8 @@ impl Default for RateStats {
             max_ns_16: 0,
             max_ns_128: 0,
             max_ns_1024: 0,
+
+            clamp: None,
         }
     }
 }
@@ -58,68 +79,354 @@ impl RateStats {
         Self::default()
     }
 
+    /// Returns a new `RateStats` that clamps every added sample between
+    /// `min_ns` and `max_ns` nanoseconds.
+    ///
+    /// This guards against glitched samples, like a zero duration caused by
+    /// the clock resolution, or a huge one caused by the process being
+    /// suspended. Since the samples are clamped on insertion, the clamping
+    /// affects both the averages and the maximums.
+    ///
+    /// # Panics
+    /// Panics if `min_ns > max_ns`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, RateStats};
+    ///
+    /// let mut s = RateStats::with_clamp(1_000, 50_000_000);
+    /// s.add_ns(0);
+    /// assert_eq![Some(Duration::microseconds(1)), s.last()];
+    /// s.add(Duration::SECOND);
+    /// assert_eq![Some(Duration::milliseconds(50)), s.last()];
+    /// s.add_ns(16_000_000);
+    /// assert_eq![Some(Duration::milliseconds(16)), s.last()];
+    ///
+    /// s.extend_ns([0; 16]);
+    /// assert_eq![Some(1_000.), s.avg_ns(16)];
+    /// assert_eq![Some(50_000_000), s.max_ns(1024)];
+    /// ```
+    pub fn with_clamp(min_ns: u64, max_ns: u64) -> Self {
+        assert![min_ns <= max_ns, "min_ns must not be greater than max_ns"];
+        Self {
+            clamp: Some((min_ns, max_ns)),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the `(min, max)` nanoseconds every sample is clamped to, if any.
+    #[inline]
+    pub fn clamp(&self) -> Option<(u64, u64)> {
+        self.clamp
+    }
+
     /// Adds a new `duration` to the stats.
     #[inline]
     pub fn add(&mut self, duration: Duration) {
-        self.avg_ring
-            .push_back(cmp::max(0_i128, duration.whole_nanoseconds()) as u64);
+        self.add_ns(cmp::max(0_i128, duration.whole_nanoseconds()) as u64);
     }
     /// Adds a new `nanoseconds` value to the stats.
     #[inline]
     pub fn add_ns(&mut self, nanoseconds: u64) {
-        self.avg_ring.push_back(nanoseconds);
+        self.avg_ring.push_back(clamp_ns(self.clamp, nanoseconds));
+    }
+
+    /// Returns the number of recorded samples, up to 1024.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// assert_eq![0, s.sample_count()];
+    /// s.extend_ns([10; 2000]);
+    /// assert_eq![1024, s.sample_count()];
+    /// ```
+    #[inline]
+    pub fn sample_count(&self) -> usize {
+        self.avg_ring.len()
+    }
+
+    /// Returns `true` if there are enough samples to fill the given `window`.
+    ///
+    /// Until then, the statistics of the window are calculated from the
+    /// available samples.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// s.extend_ns([10; 16]);
+    /// assert![s.is_warm(16)];
+    /// assert![!s.is_warm(128)];
+    /// ```
+    #[inline]
+    pub fn is_warm(&self, window: usize) -> bool {
+        self.sample_count() >= window
+    }
+
+    /// Returns the most recently added duration, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, RateStats};
+    ///
+    /// let mut s = RateStats::new();
+    /// assert_eq![None, s.last()];
+    /// s.add_ns(10);
+    /// s.add(Duration::milliseconds(5));
+    /// assert_eq![Some(Duration::milliseconds(5)), s.last()];
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<Duration> {
+        self.avg_ring
+            .back()
+            .map(|ns| Duration::nanoseconds_i128(*ns as i128))
+    }
+
+    /// Adds many `samples` in nanoseconds to the stats at once,
+    /// and then recalculates the statistics of every time window.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// // 1008 samples of 1 ms, followed by 16 samples of 2 ms
+    /// s.extend_ns(vec![1_000_000; 1008].into_iter().chain([2_000_000; 16]));
+    /// assert_eq![Some(2_000_000.), s.avg_ns(16)];
+    /// assert_eq![Some(1_125_000.), s.avg_ns(128)];
+    /// assert_eq![Some(1_015_625.), s.avg_ns(1024)];
+    /// assert_eq![Some(2_000_000), s.max_ns(1024)];
+    /// ```
+    pub fn extend_ns(&mut self, samples: impl IntoIterator<Item = u64>) {
+        let clamp = self.clamp;
+        self.avg_ring
+            .extend_back(samples.into_iter().map(|ns| clamp_ns(clamp, ns)));
+        (self.avg_16, self.max_ns_16) = self.window_stats(16);
+        (self.avg_128, self.max_ns_128) = self.window_stats(128);
+        (self.avg_1024, self.max_ns_1024) = self.window_stats(1024);
     }
 
     /// Updates the statistics for each time window that aligns with
-    /// the provided tick count.
+    /// the provided tick count, or that is not [warm][Self::is_warm] yet.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// for (tick, ns) in (1..=5).zip([10, 20, 30, 40, 50]) {
+    ///     s.add_ns(ns);
+    ///     s.update(tick);
+    /// }
+    /// assert_eq![Some(30.), s.avg_ns(16)];
+    /// assert_eq![Some(30.), s.avg_ns(128)];
+    /// assert_eq![Some(50), s.max_ns(1024)];
+    /// ```
     pub fn update(&mut self, tick_count: u64) {
-        // IMPROVE IDEA we may have a little performance gain
-        // if we could reuse %16 for %128 and that for %1024
-
-        if tick_count % 16 == 0 {
-            let mut avg_accumulator = 0_u64;
-            self.max_ns_16 = 0;
-            let mut i = self.avg_ring.iter();
-            for _ in 0..16 {
-                let val = i.next_back().unwrap_or(&0);
-                avg_accumulator += i.next_back().unwrap_or(&0);
-                self.max_ns_16 = cmp::max(self.max_ns_16, *val);
-            }
-            self.avg_16 = avg_accumulator as f64 / 16.;
+        if tick_count % 16 == 0 || !self.is_warm(16) {
+            (self.avg_16, self.max_ns_16) = self.window_stats(16);
+        }
+        if tick_count % 128 == 0 || !self.is_warm(128) {
+            (self.avg_128, self.max_ns_128) = self.window_stats(128);
+        }
+        if tick_count % 1024 == 0 || !self.is_warm(1024) {
+            (self.avg_1024, self.max_ns_1024) = self.window_stats(1024);
+        }
+    }
+
+    /// Returns the average nanoseconds of the given time `window`,
+    /// as of its last update.
+    ///
+    /// While there are less samples than the size of the window, the average
+    /// is calculated from the available samples.
+    ///
+    /// Returns `None` if the window is not one of 16, 128 or 1024.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// s.extend_ns([10, 20, 30, 40, 50]);
+    /// assert_eq![Some(30.), s.avg_ns(16)];
+    /// assert_eq![Some(30.), s.avg_ns(1024)];
+    /// assert_eq![None, s.avg_ns(100)];
+    /// ```
+    #[inline]
+    pub fn avg_ns(&self, window: usize) -> Option<f64> {
+        match window {
+            16 => Some(self.avg_16),
+            128 => Some(self.avg_128),
+            1024 => Some(self.avg_1024),
+            _ => None,
         }
+    }
 
-        if tick_count % 128 == 0 {
-            let mut avg_accumulator = 0_u64;
-            self.max_ns_128 = 0;
-            let mut i = self.avg_ring.iter();
-            for _ in 0..128 {
-                let val = i.next_back().unwrap_or(&0);
-                avg_accumulator += val;
-                self.max_ns_128 = cmp::max(self.max_ns_128, *val);
-            }
-            self.avg_128 = avg_accumulator as f64 / 128.;
+    /// Returns the maximum nanoseconds of the given time `window`,
+    /// as of its last update.
+    ///
+    /// Returns `None` if the window is not one of 16, 128 or 1024.
+    #[inline]
+    pub fn max_ns(&self, window: usize) -> Option<u64> {
+        match window {
+            16 => Some(self.max_ns_16),
+            128 => Some(self.max_ns_128),
+            1024 => Some(self.max_ns_1024),
+            _ => None,
         }
+    }
 
-        if tick_count % 1024 == 0 {
-            let mut avg_accumulator = 0_u64;
-            let mut i = self.avg_ring.iter();
-
-            self.max_ns_1024 = 0;
-            for _ in 0..1024 {
-                let val = i.next_back().unwrap_or(&0);
-                avg_accumulator += val;
-                self.max_ns_1024 = cmp::max(self.max_ns_1024, *val);
-            }
-            self.avg_1024 = avg_accumulator as f64 / 1024.;
+    /// Returns the average frames per second of the given time `window`,
+    /// as of its last update.
+    ///
+    /// Returns `0.0` if the window is not one of 16, 128 or 1024,
+    /// or if its average duration is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// s.extend_ns([16_666_667; 1024]);
+    /// assert![(s.avg_fps(16) - 60.).abs() < 0.001];
+    /// assert![(s.avg_fps(1024) - 60.).abs() < 0.001];
+    /// assert_eq![0., s.avg_fps(100)];
+    /// assert_eq![0., RateStats::new().avg_fps(16)];
+    /// ```
+    #[inline]
+    pub fn avg_fps(&self, window: usize) -> f64 {
+        self.avg_ns(window).map_or(0., tps_from_ns)
+    }
+
+    /// Returns the minimum frames per second of the given time `window`,
+    /// derived from its maximum duration, as of its last update.
+    ///
+    /// Returns `0.0` if the window is not one of 16, 128 or 1024,
+    /// or if its maximum duration is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// s.extend_ns([16_666_667; 15].into_iter().chain([33_333_333]));
+    /// assert![(s.min_fps(16) - 30.).abs() < 0.001];
+    /// assert![s.avg_fps(16) > 55.];
+    /// assert_eq![0., s.min_fps(100)];
+    /// ```
+    #[inline]
+    pub fn min_fps(&self, window: usize) -> f64 {
+        self.max_ns(window).map_or(0., |ns| tps_from_ns(ns as f64))
+    }
+
+    /// Returns a report of the statistics of every time window, keyed by the
+    /// window size, as of their last update.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// s.extend_ns([20_000_000; 1024]);
+    /// let report = s.report();
+    /// assert_eq![vec![16, 128, 1024], report.keys().copied().collect::<Vec<_>>()];
+    /// for stats in report.values() {
+    ///     assert_eq![20_000_000., stats.avg_ns];
+    ///     assert_eq![20_000_000, stats.max_ns];
+    ///     assert![(stats.avg_fps - 50.).abs() < 0.001];
+    ///     assert![(stats.min_fps - 50.).abs() < 0.001];
+    /// }
+    /// ```
+    pub fn report(&self) -> BTreeMap<usize, WindowStats> {
+        [16, 128, 1024]
+            .into_iter()
+            .map(|window| {
+                let stats = WindowStats {
+                    avg_ns: self.avg_ns(window).unwrap_or(0.),
+                    max_ns: self.max_ns(window).unwrap_or(0),
+                    avg_fps: self.avg_fps(window),
+                    min_fps: self.min_fps(window),
+                };
+                (window, stats)
+            })
+            .collect()
+    }
+
+    // Returns the average and the maximum of the last `window` samples,
+    // or of all the samples while there are less than `window` of them.
+    fn window_stats(&self, window: usize) -> (f64, u64) {
+        let (mut sum, mut max, mut count) = (0_u64, 0_u64, 0_usize);
+        for val in self.avg_ring.iter().rev().take(window) {
+            sum += val;
+            max = cmp::max(max, *val);
+            count += 1;
         }
+        if count == 0 {
+            (0., 0)
+        } else {
+            (sum as f64 / count as f64, max)
+        }
+    }
+
+    /// Returns a histogram of the recorded nanosecond samples (up to 1024),
+    /// using the given ascending `buckets` boundaries.
+    ///
+    /// The returned vector has `buckets.len() + 1` counts, where the count at
+    /// index `i` is the number of samples in the range `buckets[i-1]..buckets[i]`,
+    /// the first count is for the samples lower than `buckets[0]`, and the last
+    /// count is for the samples equal or greater than the last boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// for ns in [5, 10, 15, 16, 20, 40, 100] {
+    ///     s.add_ns(ns);
+    /// }
+    /// assert_eq![vec![1, 3, 1, 2], s.histogram(&[10, 20, 40])];
+    /// assert_eq![vec![7], s.histogram(&[])];
+    /// ```
+    pub fn histogram(&self, buckets: &[u64]) -> Vec<usize> {
+        let mut counts = vec![0; buckets.len() + 1];
+        for sample in self.avg_ring.iter() {
+            counts[buckets.partition_point(|b| b <= sample)] += 1;
+        }
+        counts
     }
 
-    /// Resets the stats.
+    /// Resets the stats, discarding all the recorded samples.
+    ///
+    /// The [`clamp`][Self::clamp] bounds are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::RateStats;
+    ///
+    /// let mut s = RateStats::new();
+    /// s.extend_ns([9_000; 16]);
+    /// s.reset();
+    /// assert_eq![None, s.last()];
+    /// assert_eq![Some(0), s.max_ns(16)];
+    ///
+    /// for _ in 0..16 {
+    ///     s.add_ns(1_000);
+    /// }
+    /// s.update(16);
+    /// assert_eq![Some(1_000.), s.avg_ns(16)];
+    /// assert_eq![Some(1_000), s.max_ns(16)];
+    /// ```
     pub fn reset(&mut self) {
+        self.avg_ring.clear();
+
         self.avg_16 = 0.0;
         self.avg_128 = 0.0;
         self.avg_1024 = 0.0;
 
+        self.max_ns_16 = 0;
         self.max_ns_128 = 0;
         self.max_ns_1024 = 0;
     }
@@ -132,13 +439,13 @@ impl RateStats {
     // - IMPROVE
     pub fn log(&self, name: &str, rate: Option<&Rate>) {
         // average tps for each window
-        let avg_16 = 1. / (self.avg_16 * NS_TO_S);
-        let avg_128 = 1. / (self.avg_128 * NS_TO_S);
-        let avg_1024 = 1. / (self.avg_1024 * NS_TO_S);
+        let avg_16 = tps_from_ns(self.avg_16);
+        let avg_128 = tps_from_ns(self.avg_128);
+        let avg_1024 = tps_from_ns(self.avg_1024);
         // minimum tps for each window
-        let min_16 = 1. / (self.max_ns_16 as f64 * NS_TO_S);
-        let min_128 = 1. / (self.max_ns_128 as f64 * NS_TO_S);
-        let min_1024 = 1. / (self.max_ns_1024 as f64 * NS_TO_S);
+        let min_16 = tps_from_ns(self.max_ns_16 as f64);
+        let min_128 = tps_from_ns(self.max_ns_128 as f64);
+        let min_1024 = tps_from_ns(self.max_ns_1024 as f64);
 
         // show % against rate's tps if avaiable
         if let Some(rate) = rate {
@@ -146,13 +453,13 @@ impl RateStats {
 
             let tps = rate.tps();
             // % deviations from base for averages
-            let pcta_16 = avg_16 / tps * 100.;
-            let pcta_128 = avg_128 / tps * 100.;
-            let pcta_1024 = avg_1024 / tps * 100.;
+            let pcta_16 = percent(avg_16, tps);
+            let pcta_128 = percent(avg_128, tps);
+            let pcta_1024 = percent(avg_1024, tps);
             // % deviations from base for minimums
-            let pctm_16 = min_16 / tps * 100.;
-            let pctm_128 = min_128 / tps * 100.;
-            let pctm_1024 = min_1024 / tps * 100.;
+            let pctm_16 = percent(min_16, tps);
+            let pctm_128 = percent(min_128, tps);
+            let pctm_1024 = percent(min_1024, tps);
 
             log::trace![
                 "[window]avg(%)|min(%) rate tps:{tps:.2} dpt:{d} \"{name}\":
@@ -164,9 +471,9 @@ impl RateStats {
         // or don't
         } else {
             // % deviations from average for minimums
-            let pctm_16 = min_16 / avg_16 * 100.;
-            let pctm_128 = min_128 / avg_128 * 100.;
-            let pctm_1024 = min_1024 / avg_1024 * 100.;
+            let pctm_16 = percent(min_16, avg_16);
+            let pctm_128 = percent(min_128, avg_128);
+            let pctm_1024 = percent(min_1024, avg_1024);
 
             log::trace![
                 "[window]avg|min rate \"{name}\":
@@ -191,3 +498,34 @@ impl RateStats {
         //         }
     }
 }
+
+// Returns the ticks per second for the given nanoseconds per tick,
+// or `0.0` if the nanoseconds are not positive.
+#[inline]
+fn tps_from_ns(ns: f64) -> f64 {
+    if ns > 0. {
+        1. / (ns * NS_TO_S)
+    } else {
+        0.
+    }
+}
+
+// Returns the `nanoseconds` clamped to the optional `(min, max)` bounds.
+#[inline]
+fn clamp_ns(clamp: Option<(u64, u64)>, nanoseconds: u64) -> u64 {
+    match clamp {
+        Some((min, max)) => nanoseconds.clamp(min, max),
+        None => nanoseconds,
+    }
+}
+
+// Returns the percentage of `value` relative to `base`,
+// or `0.0` if the base is not positive.
+#[inline]
+fn percent(value: f64, base: f64) -> f64 {
+    if base > 0. {
+        value / base * 100.
+    } else {
+        0.
+    }
+}
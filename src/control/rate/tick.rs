@@ -0,0 +1,178 @@
+// espera::rate::tick
+//
+//! Tick identifiers.
+//
+
+use core::{cmp::Ordering, fmt, hash, marker::PhantomData};
+
+/// The number of a tick of a [`Rate`][crate::all::Rate], optionally tagged
+/// with a marker type `T` to tell apart the ticks of different rates.
+///
+/// Ids with different tags can't be compared with each other, turning the
+/// accidental mixing of tick counts between rates into a compile error.
+///
+/// # Examples
+/// ```
+/// use espera::all::{Duration, Instant, Rate, TickId};
+///
+/// struct Physics;
+/// struct Render;
+///
+/// let (mut physics, mut render) = (Rate::with_millis(10), Rate::with_millis(16));
+/// physics.set_ticks(5);
+/// render.set_ticks(3);
+///
+/// let p: TickId<Physics> = physics.current_tick_id();
+/// let r: TickId<Render> = render.current_tick_id();
+/// assert![p > physics.current_tick_id::<Physics>().previous()];
+/// // p == r; // doesn't compile
+///
+/// // ids can be used wherever a tick number is expected
+/// assert_eq![physics.instant_tick(5), physics.instant_tick(p)];
+/// assert_eq![3, u64::from(r)];
+/// ```
+pub struct TickId<T = ()> {
+    tick: u64,
+    _tag: PhantomData<fn() -> T>,
+}
+
+impl<T> TickId<T> {
+    /// Returns a new tick id from the given `tick` number.
+    #[inline]
+    pub const fn new(tick: u64) -> Self {
+        Self {
+            tick,
+            _tag: PhantomData,
+        }
+    }
+
+    /// Returns the tick number.
+    #[inline]
+    pub const fn get(self) -> u64 {
+        self.tick
+    }
+
+    /// Returns the id of the previous tick, saturating at zero.
+    #[inline]
+    pub const fn previous(self) -> Self {
+        Self::new(self.tick.saturating_sub(1))
+    }
+
+    /// Returns the id of the next tick, saturating at [`u64::MAX`].
+    #[inline]
+    pub const fn next(self) -> Self {
+        Self::new(self.tick.saturating_add(1))
+    }
+}
+
+/// A type that can be used as the number of a tick.
+///
+/// It's implemented for `u64` and [`TickId`].
+///
+/// The methods of [`Rate`][crate::all::Rate] that accept a tick number take
+/// an `impl TickIndex` instead of an `impl Into<u64>`, since the latter
+/// wouldn't accept untyped integer literals, like `r.instant_tick(5)`.
+/// On the other hand, other integer types must be converted explicitly,
+/// either with [`u64::from`] or [`TickId::from`].
+///
+/// # Examples
+/// ```
+/// use espera::all::{Rate, TickId};
+///
+/// let r = Rate::with_millis(10);
+/// let tick: u32 = 5;
+/// assert_eq![r.instant_tick(5), r.instant_tick(u64::from(tick))];
+/// assert_eq![r.instant_tick(5), r.instant_tick(TickId::<()>::from(tick))];
+/// ```
+pub trait TickIndex {
+    /// Returns the tick number.
+    fn tick_index(self) -> u64;
+}
+
+impl TickIndex for u64 {
+    #[inline]
+    fn tick_index(self) -> u64 {
+        self
+    }
+}
+impl<T> TickIndex for TickId<T> {
+    #[inline]
+    fn tick_index(self) -> u64 {
+        self.tick
+    }
+}
+
+impl<T> From<TickId<T>> for u64 {
+    #[inline]
+    fn from(id: TickId<T>) -> u64 {
+        id.tick
+    }
+}
+impl<T> From<u64> for TickId<T> {
+    #[inline]
+    fn from(tick: u64) -> TickId<T> {
+        TickId::new(tick)
+    }
+}
+impl<T> From<u32> for TickId<T> {
+    #[inline]
+    fn from(tick: u32) -> TickId<T> {
+        TickId::new(tick.into())
+    }
+}
+
+// the traits are implemented manually, to avoid requiring them from `T`
+
+impl<T> Clone for TickId<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for TickId<T> {}
+
+impl<T> Default for TickId<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T> PartialEq for TickId<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.tick == other.tick
+    }
+}
+impl<T> Eq for TickId<T> {}
+
+impl<T> PartialOrd for TickId<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T> Ord for TickId<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.tick.cmp(&other.tick)
+    }
+}
+
+impl<T> hash::Hash for TickId<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.tick.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for TickId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write![f, "TickId({})", self.tick]
+    }
+}
+impl<T> fmt::Display for TickId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.tick, f)
+    }
+}
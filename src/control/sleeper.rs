@@ -6,11 +6,50 @@
 use crate::all::{Duration, Instant};
 use spin_sleep::{SpinSleeper, SpinStrategy};
 
+/// The report of a single [`Sleeper::sleep_reporting`] call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SleepReport {
+    /// The requested duration to sleep.
+    pub requested: Duration,
+    /// The real elapsed duration.
+    pub actual: Duration,
+    /// The difference between the `actual` and the `requested` durations.
+    pub overshoot: Duration,
+}
+
+/// The strategy used by a [`Sleeper`] to wait.
+///
+/// The default strategy depends on the platform: [`SpinHint`][Self::SpinHint]
+/// on windows, and [`Yield`][Self::Yield] everywhere else.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SleepStrategy {
+    /// Only uses the native sleep, which is cheap but inaccurate.
+    Native,
+    /// After native sleep, yields the thread up to its accuracy.
+    Yield,
+    /// After native sleep, spin loops with a processor hint up to its accuracy.
+    SpinHint,
+}
+
+impl Default for SleepStrategy {
+    fn default() -> Self {
+        #[cfg(windows)]
+        return Self::SpinHint;
+        #[cfg(not(windows))]
+        return Self::Yield;
+    }
+}
+
 /// A sleep manager.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Sleeper {
     //
     sleeper: SpinSleeper,
+    //
+    strategy: SleepStrategy,
+    /// The instant of the last calibration, with its number of samples
+    /// and extra nanoseconds.
+    calibration: Option<(Instant, u32, u32)>,
     // /// Accuracy in nanoseconds, of the native sleep function.
     // ///
     // /// The maximum supported accuracy is 4294 ms for [`u32::MAX`] nanoseconds.
@@ -31,20 +70,136 @@ impl Sleeper {
     ///
     /// # Arguments
     /// - `accuracy`: the accuracy of native sleep, in nanoseconds.
-    /// - `do_spin`: if true, after native sleep spin loops up to its accuracy.
+    /// - `do_spin`: if true, after native sleep spin loops up to its accuracy,
+    ///   otherwise yields the thread.
+    ///
+    /// See also [`with_strategy`][Self::with_strategy].
     pub fn new(accuracy: u32, do_spin: bool) -> Self {
+        if do_spin {
+            Self::with_strategy(accuracy, SleepStrategy::SpinHint)
+        } else {
+            Self::with_strategy(accuracy, SleepStrategy::Yield)
+        }
+    }
+
+    /// Returns a new sleeper with the provided `accuracy` duration.
+    ///
+    /// Like [`new`][Self::new], but the accuracy is clamped between `0` and
+    /// [`u32::MAX`] nanoseconds (~4.29 s).
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Sleeper};
+    ///
+    /// assert_eq![1_000_000, Sleeper::with_accuracy(Duration::milliseconds(1), true).accuracy_ns()];
+    /// assert_eq![u32::MAX, Sleeper::with_accuracy(Duration::seconds(5), false).accuracy_ns()];
+    /// assert_eq![0, Sleeper::with_accuracy(-Duration::SECOND, false).accuracy_ns()];
+    /// ```
+    pub fn with_accuracy(accuracy: Duration, do_spin: bool) -> Self {
+        let accuracy = accuracy.whole_nanoseconds().clamp(0, u32::MAX as i128) as u32;
+        Self::new(accuracy, do_spin)
+    }
+
+    /// Returns a new sleeper with the provided accuracy and `strategy`.
+    ///
+    /// # Arguments
+    /// - `accuracy`: the accuracy of native sleep, in nanoseconds.
+    /// - `strategy`: how to wait after native sleep.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, SleepStrategy, Sleeper};
+    ///
+    /// for strategy in [SleepStrategy::Native, SleepStrategy::Yield, SleepStrategy::SpinHint] {
+    ///     let s = Sleeper::with_strategy(100_000, strategy);
+    ///     assert_eq![strategy, s.strategy()];
+    ///     assert_eq![100_000, s.accuracy_ns()];
+    ///     s.sleep(Duration::MICROSECOND);
+    /// }
+    /// assert_eq![SleepStrategy::SpinHint, Sleeper::new(100_000, true).strategy()];
+    /// assert_eq![SleepStrategy::Yield, Sleeper::new(100_000, false).strategy()];
+    /// assert_eq![SleepStrategy::default(), Sleeper::default().strategy()];
+    /// ```
+    pub fn with_strategy(accuracy: u32, strategy: SleepStrategy) -> Self {
         Self {
-            sleeper: Self::new_inner_sleeper(accuracy, do_spin),
+            sleeper: Self::new_inner_sleeper(accuracy, strategy),
+            strategy,
+            calibration: None,
             // accuracy,
         }
     }
 
+    /// Returns the sleep strategy.
+    #[inline]
+    pub fn strategy(&self) -> SleepStrategy {
+        self.strategy
+    }
+
+    /// Sets the sleep `strategy`, keeping the accuracy.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{SleepStrategy, Sleeper};
+    ///
+    /// let mut s = Sleeper::new(50_000, true);
+    /// s.set_strategy(SleepStrategy::Yield);
+    /// assert_eq![SleepStrategy::Yield, s.strategy()];
+    /// assert_eq![50_000, s.accuracy_ns()];
+    /// ```
+    pub fn set_strategy(&mut self, strategy: SleepStrategy) {
+        self.sleeper = Self::new_inner_sleeper(self.accuracy_ns(), strategy);
+        self.strategy = strategy;
+    }
+
     /// Sleeps for a given positive `duration`.
     ///
     /// Does nothing if duration is not positive.
-    pub fn sleep(&self, duration: Duration) {
+    ///
+    /// Returns `true` if it actually slept, which allows detecting skipped
+    /// sleeps, e.g. because of a negative remaining time.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Sleeper};
+    ///
+    /// let s = Sleeper::default();
+    /// assert![s.sleep(Duration::MICROSECOND)];
+    /// assert![!s.sleep(Duration::ZERO)];
+    /// assert![!s.sleep(-Duration::MILLISECOND)];
+    /// ```
+    pub fn sleep(&self, duration: Duration) -> bool {
         if duration.is_positive() {
-            self.sleeper.sleep(duration.unsigned_abs());
+            match self.strategy {
+                SleepStrategy::Native => spin_sleep::native_sleep(duration.unsigned_abs()),
+                _ => self.sleeper.sleep(duration.unsigned_abs()),
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Sleeps for a given positive `duration`, and reports the real elapsed time.
+    ///
+    /// Useful for tuning the [`accuracy`][Self::accuracy].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Sleeper};
+    ///
+    /// let s = Sleeper::default();
+    /// let report = s.sleep_reporting(Duration::milliseconds(2));
+    /// assert![report.actual >= report.requested];
+    /// assert_eq![report.overshoot, report.actual - report.requested];
+    /// ```
+    pub fn sleep_reporting(&self, duration: Duration) -> SleepReport {
+        let start = Instant::now();
+        self.sleep(duration);
+        let actual = Instant::now() - start;
+        SleepReport {
+            requested: duration,
+            actual,
+            overshoot: actual - duration,
         }
     }
 
@@ -64,32 +219,112 @@ impl Sleeper {
     }
 
     // Convenience constructor for the platform-dependant inner sleeper.
-    fn new_inner_sleeper(accuracy: u32, do_spin: bool) -> SpinSleeper {
-        if do_spin {
-            SpinSleeper::new(accuracy).with_spin_strategy(SpinStrategy::SpinLoopHint)
-        } else {
-            SpinSleeper::new(accuracy).with_spin_strategy(SpinStrategy::YieldThread)
+    fn new_inner_sleeper(accuracy: u32, strategy: SleepStrategy) -> SpinSleeper {
+        match strategy {
+            SleepStrategy::SpinHint => {
+                SpinSleeper::new(accuracy).with_spin_strategy(SpinStrategy::SpinLoopHint)
+            }
+            // the native strategy doesn't use the spin sleeper, except for its accuracy
+            SleepStrategy::Yield | SleepStrategy::Native => {
+                SpinSleeper::new(accuracy).with_spin_strategy(SpinStrategy::YieldThread)
+            }
         }
     }
 }
 
 impl Sleeper {
+    // The default number of samples used when recalibrating for the first time.
+    const DEFAULT_CALIBRATION_SAMPLES: u32 = 16;
+
     /// Measures the accuracy of native sleep by averaging multiple samples.
     ///
+    /// Does nothing if `num_samples` is `0`.
+    ///
     /// ## Arguments
     /// num_samples: the number of samples needed to calculate the mean duration.
     /// extra_nanos: the extra nanoseconds to add to the final value.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::Sleeper;
+    ///
+    /// let mut s = Sleeper::default();
+    /// let accuracy = s.accuracy_ns();
+    /// s.calculate_accuracy(0, 0);
+    /// assert_eq![accuracy, s.accuracy_ns()];
+    /// ```
     #[inline]
     pub fn calculate_accuracy(&mut self, num_samples: u32, extra_nanos: u32) {
-        let mut durations = vec![];
-
+        if num_samples == 0 {
+            return;
+        }
+        let mut samples = vec![];
         for _ in 0..num_samples {
-            let d = Self::sample_sleep_accuracy();
-            durations.push(d.whole_nanoseconds() as u32);
+            samples.push(Self::sample_sleep_accuracy());
         }
-        let mean_accuracy = Self::mean(durations.as_slice());
+        self.calculate_accuracy_from_samples(&samples, extra_nanos);
+        self.calibration = Some((Instant::now(), num_samples, extra_nanos));
+    }
 
-        self.sleeper = Self::new_inner_sleeper(mean_accuracy.saturating_add(extra_nanos), true);
+    /// Sets the accuracy of native sleep by averaging the provided `samples`.
+    ///
+    /// Each sample is clamped between `0` and [`u32::MAX`] nanoseconds
+    /// (~4.29 s), so that a single oversized sample (e.g. because the thread
+    /// was descheduled) can't wrap around and poison the mean.
+    ///
+    /// Does nothing if there are no `samples`. The [`strategy`][Self::strategy]
+    /// is kept.
+    ///
+    /// ## Arguments
+    /// samples: the measured durations of the shortest native sleep.
+    /// extra_nanos: the extra nanoseconds to add to the final value.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Sleeper};
+    ///
+    /// let mut s = Sleeper::default();
+    /// s.calculate_accuracy_from_samples(&[Duration::seconds(10)], 0);
+    /// assert_eq![u32::MAX, s.accuracy_ns()];
+    ///
+    /// s.calculate_accuracy_from_samples(&[Duration::microseconds(50), Duration::microseconds(70)], 5);
+    /// assert_eq![60_005, s.accuracy_ns()];
+    /// ```
+    pub fn calculate_accuracy_from_samples(&mut self, samples: &[Duration], extra_nanos: u32) {
+        let nanos = samples
+            .iter()
+            .map(|d| d.whole_nanoseconds().clamp(0, u32::MAX as i128) as u32);
+        if let Some(mean_accuracy) = Self::mean(nanos) {
+            self.sleeper =
+                Self::new_inner_sleeper(mean_accuracy.saturating_add(extra_nanos), self.strategy);
+        }
+    }
+
+    /// Recalculates the accuracy if more than `every` duration has elapsed
+    /// since the last calibration.
+    ///
+    /// It reuses the arguments of the last call to
+    /// [`calculate_accuracy`][Self::calculate_accuracy],
+    /// or a default of 16 samples with no extra nanoseconds, if there wasn't any.
+    ///
+    /// Returns `true` if the accuracy was recalculated.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Sleeper};
+    ///
+    /// let mut s = Sleeper::default();
+    /// assert![s.maybe_recalibrate(Duration::SECOND)];
+    /// assert![!s.maybe_recalibrate(Duration::SECOND)];
+    /// ```
+    pub fn maybe_recalibrate(&mut self, every: Duration) -> bool {
+        let (num_samples, extra_nanos) = match self.calibration {
+            Some((last, _, _)) if Instant::now() - last <= every => return false,
+            Some((_, num_samples, extra_nanos)) => (num_samples, extra_nanos),
+            None => (Self::DEFAULT_CALIBRATION_SAMPLES, 0),
+        };
+        self.calculate_accuracy(num_samples, extra_nanos);
+        true
     }
 
     // Returns the real time duration passed after trying to sleep the minimum
@@ -102,11 +337,15 @@ impl Sleeper {
         end - start
     }
 
-    // Calculates the mean of a slice of u32 values.
+    // Calculates the mean of a list of u32 values, accumulating in u64.
+    //
+    // Returns `None` if the list is empty.
     #[inline]
-    fn mean(list: &[u32]) -> u32 {
-        let sum: u32 = Iterator::sum(list.iter());
-        (f64::from(sum) / (list.len() as f64)) as u32
+    fn mean(list: impl Iterator<Item = u32>) -> Option<u32> {
+        let (sum, count) = list.fold((0_u64, 0_u64), |(sum, count), v| {
+            (sum + u64::from(v), count + 1)
+        });
+        sum.checked_div(count).map(|mean| mean as u32)
     }
 
     /// Returns the size of the type, in bytes.
@@ -0,0 +1,116 @@
+// espera::control::stopwatch
+//
+//! A stopwatch for simple interval timing.
+//
+
+use crate::all::{Duration, Instant};
+
+/// A stopwatch for simple interval timing, with laps.
+///
+/// It only counts the time while it's running, so it can be stopped and
+/// started again to resume the count.
+///
+/// # Examples
+/// ```
+/// use espera::all::{Duration, Stopwatch};
+/// use std::thread::sleep;
+///
+/// let mut sw = Stopwatch::new();
+/// sw.start();
+/// sleep(std::time::Duration::from_millis(10));
+/// let lap1 = sw.lap();
+/// sleep(std::time::Duration::from_millis(20));
+/// let lap2 = sw.lap();
+/// sleep(std::time::Duration::from_millis(5));
+/// let total = sw.stop();
+///
+/// assert![lap1 >= Duration::milliseconds(10)];
+/// assert![lap2 >= Duration::milliseconds(20)];
+/// assert_eq![&[lap1, lap2], sw.laps()];
+/// assert![total >= lap1 + lap2 + Duration::milliseconds(5)];
+///
+/// // it doesn't count while stopped
+/// sleep(std::time::Duration::from_millis(5));
+/// assert![!sw.is_running()];
+/// assert_eq![total, sw.elapsed()];
+///
+/// sw.reset();
+/// assert_eq![Duration::ZERO, sw.elapsed()];
+/// assert![sw.laps().is_empty()];
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stopwatch {
+    /// The instant when it was last started, while it's running.
+    started: Option<Instant>,
+    /// The time counted before it was last started.
+    counted: Duration,
+    /// The elapsed time at the end of the last lap.
+    last_lap: Duration,
+    /// The duration of each lap.
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    /// Returns a new stopped stopwatch.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts counting the time, or resumes it if it was stopped.
+    ///
+    /// Does nothing if it's already running.
+    pub fn start(&mut self) {
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    /// Stops counting the time, and returns the total elapsed time.
+    ///
+    /// Does nothing if it's already stopped.
+    pub fn stop(&mut self) -> Duration {
+        if let Some(started) = self.started.take() {
+            self.counted += Instant::now() - started;
+        }
+        self.counted
+    }
+
+    /// Records a new lap, and returns its duration, which is the time counted
+    /// since the previous lap, or since the start if it's the first one.
+    pub fn lap(&mut self) -> Duration {
+        let elapsed = self.elapsed();
+        let lap = elapsed - self.last_lap;
+        self.last_lap = elapsed;
+        self.laps.push(lap);
+        lap
+    }
+
+    /// Stops the stopwatch, and resets the elapsed time and the laps.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the total elapsed time, while it was running.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        match self.started {
+            Some(started) => self.counted + (Instant::now() - started),
+            None => self.counted,
+        }
+    }
+
+    /// Returns `true` if it's running.
+    #[must_use]
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Returns the durations of the recorded laps.
+    #[must_use]
+    #[inline]
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+}
@@ -0,0 +1,138 @@
+// espera::control::timer
+//
+//! A countdown timer.
+//
+
+use crate::all::{Duration, Instant};
+
+/// A countdown timer, useful for cooldowns and timeouts.
+///
+/// It counts down from its duration towards a deadline, and can be paused.
+///
+/// # Examples
+/// ```
+/// use espera::all::{Duration, Timer};
+///
+/// let timer = Timer::new(Duration::seconds(2));
+/// let start = timer.deadline() - timer.duration();
+///
+/// // before expiry
+/// let now = start + Duration::milliseconds(500);
+/// assert_eq![Duration::milliseconds(1_500), timer.remaining(now)];
+/// assert![!timer.is_expired(now)];
+///
+/// // at expiry
+/// let now = start + Duration::seconds(2);
+/// assert_eq![Duration::ZERO, timer.remaining(now)];
+/// assert![timer.is_expired(now)];
+///
+/// // after expiry
+/// let now = start + Duration::seconds(3);
+/// assert_eq![Duration::ZERO, timer.remaining(now)];
+/// assert![timer.is_expired(now)];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timer {
+    /// The duration of the countdown.
+    duration: Duration,
+    /// The instant when it expires, while it's not paused.
+    deadline: Instant,
+    /// The remaining time, while it's paused.
+    paused: Option<Duration>,
+}
+
+impl Timer {
+    /// Returns a new timer that starts counting down the given `duration`
+    /// from now.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            deadline: Instant::now() + duration,
+            paused: None,
+        }
+    }
+
+    /// Returns the duration of the countdown.
+    #[must_use]
+    #[inline]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the instant when it expires, or expired.
+    ///
+    /// While it's paused, this is the deadline it would have had if it
+    /// had not been paused.
+    #[must_use]
+    #[inline]
+    pub const fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Returns the remaining time at the given `now`, which is zero once
+    /// it has expired.
+    #[must_use]
+    pub fn remaining(&self, now: Instant) -> Duration {
+        match self.paused {
+            Some(remaining) => remaining,
+            None => (self.deadline - now).max(Duration::ZERO),
+        }
+    }
+
+    /// Returns `true` if it has expired at the given `now`.
+    #[must_use]
+    #[inline]
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.remaining(now) == Duration::ZERO
+    }
+
+    /// Restarts the countdown of the whole duration from now,
+    /// unpausing it if it was paused.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.duration);
+    }
+
+    /// Pauses the countdown at the given `now`.
+    ///
+    /// Does nothing if it's already paused.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Timer};
+    ///
+    /// let mut timer = Timer::new(Duration::seconds(2));
+    /// let start = timer.deadline() - timer.duration();
+    ///
+    /// timer.pause(start + Duration::seconds(1));
+    /// assert![timer.is_paused()];
+    /// let later = start + Duration::seconds(10);
+    /// assert_eq![Duration::seconds(1), timer.remaining(later)];
+    /// assert![!timer.is_expired(later)];
+    ///
+    /// timer.resume(later);
+    /// assert![!timer.is_paused()];
+    /// assert_eq![later + Duration::seconds(1), timer.deadline()];
+    /// assert![timer.is_expired(later + Duration::seconds(1))];
+    /// ```
+    pub fn pause(&mut self, now: Instant) {
+        if self.paused.is_none() {
+            self.paused = Some(self.remaining(now));
+        }
+    }
+
+    /// Resumes the countdown at the given `now`, from where it was paused.
+    ///
+    /// Does nothing if it's not paused.
+    pub fn resume(&mut self, now: Instant) {
+        if let Some(remaining) = self.paused.take() {
+            self.deadline = now + remaining;
+        }
+    }
+
+    /// Returns `true` if it's paused.
+    #[must_use]
+    #[inline]
+    pub const fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+}
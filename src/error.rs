@@ -15,10 +15,21 @@ pub type EsperaResult<N> = result::Result<N, EsperaError>;
 #[non_exhaustive]
 #[derive(Debug)]
 pub enum EsperaError {
+    /// A value is outside of the supported or representable range.
+    OutOfBounds(&'static str),
+
+    /// A string could not be parsed.
+    Parse(&'static str),
+
     /// An error involving the encoding of a rate's name.
     #[cfg(feature = "std")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
     RateName(EncodeError),
+
+    /// A rate with the same name already exists.
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    DuplicateRate,
 }
 
 mod core_impls {
@@ -31,11 +42,13 @@ mod core_impls {
     impl fmt::Display for EsperaError {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             match self {
+                EsperaError::OutOfBounds(s) => f.write_str(s),
+                EsperaError::Parse(s) => f.write_str(s),
+
                 #[cfg(feature = "std")]
                 EsperaError::RateName(r) => fmt::Debug::fmt(r, f),
-
-                #[allow(unreachable_patterns)] // TEMP
-                _ => write!(f, ""),
+                #[cfg(feature = "std")]
+                EsperaError::DuplicateRate => f.write_str("A rate with that name already exists."),
             }
         }
     }
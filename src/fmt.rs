@@ -3,25 +3,132 @@
 //! Formatting.
 //
 
+use crate::{
+    error::{EsperaError, EsperaResult},
+    time::Duration,
+};
+use core::fmt;
+
 #[cfg(feature = "alloc")]
 use alloc::{format, string::String};
 
-/// Returns the time code as `HH:MM:SS:MIL`.
+#[cfg_attr(feature = "alloc", doc = "The smallest unit printed by [`timecode`].")]
+#[cfg_attr(
+    not(feature = "alloc"),
+    doc = "The smallest unit printed by `timecode`."
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precision {
+    /// Whole seconds.
+    Seconds,
+    /// Milliseconds, with 3 decimal digits.
+    #[default]
+    Millis,
+    /// Microseconds, with 6 decimal digits.
+    Micros,
+    /// Nanoseconds, with 9 decimal digits.
+    Nanos,
+}
+
+impl Precision {
+    /// Returns the number of decimal digits of the seconds.
+    #[inline]
+    pub const fn digits(self) -> usize {
+        match self {
+            Precision::Seconds => 0,
+            Precision::Millis => 3,
+            Precision::Micros => 6,
+            Precision::Nanos => 9,
+        }
+    }
+
+    /// Returns the number of nanoseconds of the smallest unit.
+    #[inline]
+    pub const fn unit_ns(self) -> u64 {
+        match self {
+            Precision::Seconds => 1_000_000_000,
+            Precision::Millis => 1_000_000,
+            Precision::Micros => 1_000,
+            Precision::Nanos => 1,
+        }
+    }
+}
+
+/// Returns the time code of the given nanoseconds as `HH:MM:SS.fff`,
+/// with the fractional digits defined by the `precision`.
+///
+/// The hours are omitted if zero, and the remainder smaller than the
+/// `precision` unit is rounded to the nearest unit.
+///
+/// # Examples
+/// ```
+/// use espera::fmt::{timecode, Precision};
+///
+/// let ns = 3_723_456_789_012; // 1h 2m 3s 456ms 789µs 12ns
+/// assert_eq!["01:02:03", timecode(ns, Precision::Seconds)];
+/// assert_eq!["01:02:03.457", timecode(ns, Precision::Millis)];
+/// assert_eq!["01:02:03.456789", timecode(ns, Precision::Micros)];
+/// assert_eq!["01:02:03.456789012", timecode(ns, Precision::Nanos)];
+///
+/// assert_eq!["01:00", timecode(59_999_999_999, Precision::Seconds)];
+/// assert_eq!["00:59.999", timecode(59_999_000_000, Precision::Millis)];
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn timecode(ns: u64, precision: Precision) -> String {
+    let unit = precision.unit_ns();
+    let units_per_second = 1_000_000_000 / unit;
+
+    // round the remainder to the nearest unit
+    let units = ns / unit + u64::from((ns % unit) * 2 >= unit);
+    let (mut ts, fract) = (units / units_per_second, units % units_per_second);
+
+    let h = ts / 3600;
+    ts %= 3600;
+    let m = ts / 60;
+    let s = ts % 60;
+
+    let digits = precision.digits();
+    match (h > 0, digits > 0) {
+        (true, true) => format!["{h:02}:{m:02}:{s:02}.{fract:0digits$}"],
+        (true, false) => format!["{h:02}:{m:02}:{s:02}"],
+        (false, true) => format!["{m:02}:{s:02}.{fract:0digits$}"],
+        (false, false) => format!["{m:02}:{s:02}"],
+    }
+}
+
+/// Returns the time code as `HH:MM:SS.mmm`, rounded to the nearest millisecond.
+///
+/// The hours are omitted if zero. Negative and `NaN` seconds are treated as
+/// zero.
+///
+/// # Examples
+/// ```
+/// use espera::fmt::timecode_f64;
+///
+/// assert_eq!["00:01.300", timecode_f64(1.3)];
+/// assert_eq!["00:02.300", timecode_f64(2.3)];
+/// assert_eq!["00:59.999", timecode_f64(59.999)];
+/// assert_eq!["01:00.000", timecode_f64(59.9996)];
+/// assert_eq!["01:02:03.457", timecode_f64(3723.4567)];
+/// assert_eq!["00:00.000", timecode_f64(-1.5)];
+/// assert_eq!["00:00.000", timecode_f64(f64::NAN)];
+/// ```
 #[cfg(any(feature = "std", all(feature = "alloc", feature = "libm")))]
 #[cfg_attr(
     feature = "nightly",
     doc(cfg(any(feature = "std", all(feature = "alloc", feature = "libm"))))
 )]
 pub fn timecode_f64(seconds: f64) -> String {
+    // rounds the total milliseconds, so that the fractional part doesn't
+    // lose a millisecond to floating point errors (e.g. 2.3 -> 2.2999…)
     #[cfg(feature = "std")]
-    let ms = (seconds.fract() * 1000.) as u64;
+    let total_ms = (seconds * 1000.).round() as u64;
     #[cfg(all(not(feature = "std"), feature = "libm"))]
-    let ms = (libm::modf(seconds).0 * 1000.) as u64;
+    let total_ms = libm::round(seconds * 1000.) as u64;
 
-    #[cfg(feature = "std")]
-    let mut ts = seconds.trunc() as u64;
-    #[cfg(all(not(feature = "std"), feature = "libm"))]
-    let mut ts = libm::trunc(seconds) as u64;
+    let ms = total_ms % 1000;
+    let mut ts = total_ms / 1000;
 
     let h = ts / 3600;
     ts %= 3600;
@@ -44,21 +151,181 @@ pub fn timecode_f64(seconds: f64) -> String {
 }
 
 /// Returns the time code, up to seconds, as `1s 012ms 012µs 012345ns`.
+///
+/// See also [`write_timecode_ns`] for a version that doesn't allocate.
 // THINK: sub-second
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
 pub fn timecode_ns_u64(ns: u64) -> String {
+    let mut s = String::new();
+    // writing to a String never fails
+    let _ = write_timecode_ns(ns, &mut s);
+    s
+}
+
+/// Writes the time code, up to seconds, as `1s 012ms 012µs 012345ns`,
+/// into the given `out` writer, without allocating.
+///
+/// # Errors
+/// Returns an error if the writer fails, e.g. if it runs out of space.
+///
+/// # Examples
+/// ```
+/// use core::fmt::{self, Write};
+/// use espera::fmt::write_timecode_ns;
+///
+/// // a fixed-size stack buffer
+/// struct Buf { bytes: [u8; 32], len: usize }
+/// impl Write for Buf {
+///     fn write_str(&mut self, s: &str) -> fmt::Result {
+///         let end = self.len + s.len();
+///         self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+///         self.len = end;
+///         Ok(())
+///     }
+/// }
+///
+/// let mut buf = Buf { bytes: [0; 32], len: 0 };
+/// write_timecode_ns(1_012_013_014, &mut buf).unwrap();
+/// assert_eq!["1s 012ms 013µs 000014ns", core::str::from_utf8(&buf.bytes[..buf.len]).unwrap()];
+///
+/// let mut small = Buf { bytes: [0; 32], len: 30 };
+/// assert![write_timecode_ns(1_012_013_014, &mut small).is_err()];
+/// ```
+pub fn write_timecode_ns(ns: u64, out: &mut dyn fmt::Write) -> fmt::Result {
     let (us, ns_rem) = (ns / 1000, ns % 1000);
     let (ms, us_rem) = (us / 1000, us % 1000);
     let (s, ms_rem) = (ms / 1000, ms % 1000);
 
     if s > 0 {
-        format!["{s}s {ms_rem:03}ms {us_rem:03}µs {ns_rem:06}ns"]
+        write![out, "{s}s {ms_rem:03}ms {us_rem:03}µs {ns_rem:06}ns"]
     } else if ms > 0 {
-        format!["{ms_rem}ms {us_rem:03}µs {ns_rem:06}ns"]
+        write![out, "{ms_rem}ms {us_rem:03}µs {ns_rem:06}ns"]
     } else if us > 0 {
-        format!["{us_rem}µs {ns_rem:06}ns"]
+        write![out, "{us_rem}µs {ns_rem:06}ns"]
     } else {
-        format!["{ns_rem:06}ns"]
+        write![out, "{ns_rem:06}ns"]
+    }
+}
+
+/// Returns the english ordinal suffix of `n`: `"st"`, `"nd"`, `"rd"` or `"th"`.
+///
+/// # Examples
+/// ```
+/// use espera::fmt::ordinal;
+///
+/// assert_eq!["st", ordinal(1)];
+/// assert_eq!["nd", ordinal(2)];
+/// assert_eq!["rd", ordinal(3)];
+/// assert_eq!["th", ordinal(4)];
+/// assert_eq!["th", ordinal(11)];
+/// assert_eq!["th", ordinal(12)];
+/// assert_eq!["th", ordinal(13)];
+/// assert_eq!["st", ordinal(21)];
+/// assert_eq!["th", ordinal(111)];
+/// assert_eq!["nd", ordinal(122)];
+/// assert_eq!["th", ordinal(0)];
+/// ```
+pub const fn ordinal(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Returns `n` followed by its english [`ordinal`] suffix, like `"4th"`.
+///
+/// # Examples
+/// ```
+/// use espera::fmt::ordinal_string;
+///
+/// assert_eq!["1st", ordinal_string(1)];
+/// assert_eq!["2nd", ordinal_string(2)];
+/// assert_eq!["3rd", ordinal_string(3)];
+/// assert_eq!["4th", ordinal_string(4)];
+/// assert_eq!["11th", ordinal_string(11)];
+/// assert_eq!["12th", ordinal_string(12)];
+/// assert_eq!["13th", ordinal_string(13)];
+/// assert_eq!["21st", ordinal_string(21)];
+/// assert_eq!["111th", ordinal_string(111)];
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn ordinal_string(n: u32) -> String {
+    format!["{n}{}", ordinal(n)]
+}
+
+/// Parses a human readable duration, like `"90s"`, `"1h30m"` or `"250ms"`.
+///
+/// The string is a sequence of segments, each one made of an integer followed
+/// by a unit, which are added together. The supported units are `h`, `m`,
+/// `s`, `ms`, `us` (or `µs`) and `ns`. Whitespace between segments is ignored.
+///
+/// # Errors
+/// Returns [`EsperaError::Parse`] if the string is empty, a segment lacks its
+/// number or its unit, or the unit is unknown; and
+/// [`EsperaError::OutOfBounds`] if the total doesn't fit in a [`Duration`].
+///
+/// # Examples
+/// ```
+/// use espera::{all::Duration, fmt::parse_duration};
+///
+/// assert_eq![Duration::seconds(90), parse_duration("90s").unwrap()];
+/// assert_eq![Duration::minutes(90), parse_duration("1h30m").unwrap()];
+/// assert_eq![Duration::milliseconds(250), parse_duration("250ms").unwrap()];
+/// assert_eq![
+///     Duration::new(61, 2_003_004),
+///     parse_duration("1m 1s 2ms 3µs 4ns").unwrap()
+/// ];
+/// assert_eq![Duration::microseconds(1500), parse_duration("1ms500us").unwrap()];
+///
+/// assert![parse_duration("").is_err()];
+/// assert![parse_duration("10").is_err()];
+/// assert![parse_duration("ms").is_err()];
+/// assert![parse_duration("3d").is_err()];
+/// assert![parse_duration("-5s").is_err()];
+/// ```
+pub fn parse_duration(s: &str) -> EsperaResult<Duration> {
+    const OVERFLOW: EsperaError = EsperaError::OutOfBounds("The duration is too big.");
+
+    let mut total_ns: i128 = 0;
+    let mut rest = s.trim_start();
+    if rest.is_empty() {
+        return Err(EsperaError::Parse("The duration is empty."));
     }
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Err(EsperaError::Parse("Expected a number before the unit."));
+        }
+        let (number, tail) = rest.split_at(digits);
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit() || c.is_whitespace())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+
+        let unit_ns: i128 = match unit {
+            "h" => 3_600_000_000_000,
+            "m" => 60_000_000_000,
+            "s" => 1_000_000_000,
+            "ms" => 1_000_000,
+            "us" | "µs" => 1_000,
+            "ns" => 1,
+            "" => return Err(EsperaError::Parse("Expected a unit after the number.")),
+            _ => return Err(EsperaError::Parse("Unknown duration unit.")),
+        };
+        let number: i128 = number.parse().map_err(|_| OVERFLOW)?;
+        total_ns = number
+            .checked_mul(unit_ns)
+            .and_then(|ns| total_ns.checked_add(ns))
+            .ok_or(OVERFLOW)?;
+
+        rest = tail.trim_start();
+    }
+
+    let seconds = i64::try_from(total_ns / 1_000_000_000).map_err(|_| OVERFLOW)?;
+    Ok(Duration::new(seconds, (total_ns % 1_000_000_000) as i32))
 }
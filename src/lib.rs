@@ -1,6 +1,65 @@
 // espera::lib
 //
 //! Time management.
+//!
+//! The methods that only query a value, like
+//! [`UnixTime::to_ymdhms`][time::UnixTime::to_ymdhms] or `Rate::ticks`, and
+//! the ones whose result shouldn't be ignored, like `Rate::do_tick`, are
+//! marked as `#[must_use]`, so that discarding their result by accident warns:
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//! use espera::all::UnixTime;
+//!
+//! UnixTime::new(0).to_ymdhms();
+//! ```
+//!
+//! # Features
+//!
+//! These are the supported combinations of features:
+//!
+//! - none: the [`calendar`] types, [`UnixTime`][time::UnixTime],
+//!   [`UnixTime32`][time::UnixTime32], and the [`fmt`] functions that don't
+//!   allocate.
+//! - `alloc`: adds the [`fmt`] functions returning a `String`.
+//! - `alloc` + `libm`, or `no_std` + `alloc`: adds `fmt::timecode_f64`.
+//! - `no_std` + `unsafe` + `libc`: adds `UnixTime::now`, on unix platforms.
+//! - `std`: adds the `control` module and everything above.
+//! - `safe`, which can't be combined with `unsafe`: forbids unsafe code.
+//! - `chrono`, `jiff` and `proptest`: can be combined with any of the above.
+//!
+//! The `std` and `no_std` features can't be enabled at the same time.
+//!
+//! The following smoke test exercises the core path of each combination,
+//! and is run by `cargo test --doc --no-default-features -F=<features>`:
+//!
+//! ```
+//! use espera::all::*;
+//!
+//! // always available
+//! let ut = UnixTime::from_ymdhms(2024, 2, 29, 12, 30, 0).unwrap();
+//! assert_eq![(2024, 2, 29, 12, 30, 0), ut.to_ymdhms()];
+//! assert_eq![Weekday::Thursday, Date::new(2024, Month::February, 29).unwrap().weekday()];
+//! assert_eq![29, Month::February.len(true)];
+//! assert_eq!["th", ordinal(29)];
+//!
+//! #[cfg(feature = "alloc")]
+//! assert_eq!["01:02.345", timecode(62_345_000_000, Precision::Millis)];
+//!
+//! #[cfg(any(feature = "std", all(feature = "alloc", feature = "libm")))]
+//! assert_eq!["00:02.300", timecode_f64(2.3)];
+//!
+//! #[cfg(any(feature = "std", all(feature = "unsafe", feature = "libc")))]
+//! assert![UnixTime::now() > ut];
+//!
+//! #[cfg(feature = "std")]
+//! {
+//!     let mut r = Rate::with_millis(1);
+//!     let t = Instant::now();
+//!     r.set_last_tick(t);
+//!     assert![r.do_tick(t + Duration::milliseconds(1)).is_some()];
+//! }
+//! ```
 //
 
 // warnings
@@ -8,7 +67,8 @@
 #![allow(
     clippy::needless_return,
     clippy::module_inception,
-    non_upper_case_globals
+    non_upper_case_globals,
+    unexpected_cfgs, // for deprecate_feature
 )]
 // environment
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -34,11 +94,14 @@ pub mod time;
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
 pub mod control;
 
+#[cfg(feature = "proptest")]
+mod arbitrary;
+
 /// All items are reexported here.
 pub mod all {
     #[doc(inline)]
     pub use super::{
-        calendar::{Month, Weekday},
+        calendar::{Date, Month, MonthSet, Season, Weekday, WeekdaySet},
         error::*,
         fmt::*,
         time::*,
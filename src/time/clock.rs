@@ -0,0 +1,47 @@
+// espera::time::clock
+//
+//! Monotonic clock helpers.
+//
+
+use crate::time::Duration;
+use std::{sync::OnceLock, time::Instant};
+
+// The reference instant, initialized on the first call to `now_nanos`.
+static REFERENCE: OnceLock<Instant> = OnceLock::new();
+
+/// Returns the monotonic nanoseconds elapsed since a reference instant,
+/// which is lazily initialized on the first call.
+///
+/// It's a cheap integer timestamp suitable for logging, which saturates at
+/// [`u64::MAX`] nanoseconds (~584 years).
+///
+/// # Examples
+/// ```
+/// use espera::time::now_nanos;
+///
+/// let first = now_nanos();
+/// let second = now_nanos();
+/// assert![second >= first];
+/// ```
+pub fn now_nanos() -> u64 {
+    let reference = REFERENCE.get_or_init(Instant::now);
+    u64::try_from(reference.elapsed().as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Returns the duration elapsed since the given `start` timestamp,
+/// obtained from [`now_nanos`].
+///
+/// Returns [`Duration::ZERO`] if `start` is in the future.
+///
+/// # Examples
+/// ```
+/// use espera::time::{elapsed_since, now_nanos, Duration};
+///
+/// let start = now_nanos();
+/// std::thread::sleep(std::time::Duration::from_millis(1));
+/// assert![elapsed_since(start) >= Duration::MILLISECOND];
+/// assert_eq![Duration::ZERO, elapsed_since(u64::MAX)];
+/// ```
+pub fn elapsed_since(start: u64) -> Duration {
+    Duration::nanoseconds_i128(now_nanos().saturating_sub(start) as i128)
+}
@@ -0,0 +1,48 @@
+// espera::time::consts
+//
+//! Common duration constants.
+//
+
+use crate::time::Duration;
+
+/// One microsecond.
+pub const MICROSECOND: Duration = Duration::MICROSECOND;
+
+/// One millisecond.
+pub const MILLISECOND: Duration = Duration::MILLISECOND;
+
+/// One second.
+pub const SECOND: Duration = Duration::SECOND;
+
+/// The duration of a frame at 30 Hz, rounded to the nearest nanosecond.
+///
+/// # Examples
+/// ```
+/// use espera::time::{Duration, FRAME_30HZ};
+///
+/// let exact = Duration::seconds_f64(1.0 / 30.0);
+/// assert![(FRAME_30HZ - exact).abs() <= Duration::NANOSECOND];
+/// ```
+pub const FRAME_30HZ: Duration = Duration::new(0, 33_333_333);
+
+/// The duration of a frame at 60 Hz, rounded to the nearest nanosecond.
+///
+/// # Examples
+/// ```
+/// use espera::time::{Duration, FRAME_60HZ};
+///
+/// let exact = Duration::seconds_f64(1.0 / 60.0);
+/// assert![(FRAME_60HZ - exact).abs() <= Duration::NANOSECOND];
+/// ```
+pub const FRAME_60HZ: Duration = Duration::new(0, 16_666_667);
+
+/// The duration of a frame at 120 Hz, rounded to the nearest nanosecond.
+///
+/// # Examples
+/// ```
+/// use espera::time::{Duration, FRAME_120HZ};
+///
+/// let exact = Duration::seconds_f64(1.0 / 120.0);
+/// assert![(FRAME_120HZ - exact).abs() <= Duration::NANOSECOND];
+/// ```
+pub const FRAME_120HZ: Duration = Duration::new(0, 8_333_333);
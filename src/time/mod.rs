@@ -1,16 +1,34 @@
 // espera::time
 //
 //! Time related types.
+//!
+//! All the types in this module are `Send` and `Sync`.
 //
 
 // mod duration;
+mod consts;
 mod unix;
 
+#[cfg(feature = "std")]
+mod clock;
+
+pub use consts::{FRAME_120HZ, FRAME_30HZ, FRAME_60HZ, MICROSECOND, MILLISECOND, SECOND};
 pub use unix::{UnixTime, UnixTime32};
 
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub use clock::{elapsed_since, now_nanos};
+
 /// (re-exported from the [`time`] crate).
 pub use time::Duration;
 
 /// (re-exported from the [`time`] crate).
 #[cfg(feature = "std")]
 pub use time::Instant;
+
+// Ensures at compile time the types remain `Send` and `Sync`.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<UnixTime>();
+    assert_send_sync::<UnixTime32>();
+};
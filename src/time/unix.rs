@@ -9,12 +9,49 @@
 //! Unix time.
 //
 
-use crate::calendar::{is_leap_year, Month};
-use core::{convert::TryFrom, fmt, num::TryFromIntError};
+use crate::{
+    calendar::{civil_from_days, days_from_civil, days_in_year, is_leap_year, Month},
+    error::{EsperaError, EsperaResult},
+    time::Duration,
+};
+use core::{convert::TryFrom, fmt, num::TryFromIntError, str::FromStr};
+
+#[cfg(feature = "std")]
+use crate::time::Instant;
 
 /// 64-bit Unix time, supporting negative values.
 ///
 /// Stores number of seconds since the Unix Epoch (`1970-01-01 00:00:00 UTC`).
+///
+/// # Formatting
+/// The [`Display`][fmt::Display] implementation prints the human readable form,
+/// while its alternate form (`{:#}`) prints the raw number of seconds.
+/// The [`LowerHex`][fmt::LowerHex] and [`UpperHex`][fmt::UpperHex]
+/// implementations print the raw seconds in hexadecimal, with negative values
+/// in two's complement.
+///
+/// ```
+/// use espera::all::UnixTime;
+///
+/// let ut = UnixTime::new(915148799);
+/// assert_eq!["1998-12-31_23:59:59", format!["{ut}"]];
+/// assert_eq!["915148799", format!["{ut:#}"]];
+/// assert_eq!["368c0fff", format!["{ut:x}"]];
+/// assert_eq!["0x368C0FFF", format!["{ut:#X}"]];
+/// assert_eq!["-1", format!["{:#}", UnixTime::new(-1)]];
+/// ```
+///
+/// Negative years are prefixed with `-`, following the astronomical year
+/// numbering of ISO 8601, where `0000` is 1 BCE and `-0001` is 2 BCE:
+/// ```
+/// use espera::all::UnixTime;
+///
+/// assert_eq!["0000-01-01_00:00:00", UnixTime::new(-62167219200).to_string()];
+/// assert_eq!["-0001-12-31_23:59:59", UnixTime::new(-62167219201).to_string()];
+/// assert_eq!["-146138510344-07-14_16:14:56", UnixTime::new(i64::MIN / 2).to_string()];
+/// assert_eq!["-292277022657-01-27_08:29:52", UnixTime::MIN.to_string()];
+/// assert_eq!["292277026596-12-04_15:30:07", UnixTime::MAX.to_string()];
+/// ```
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UnixTime {
     pub seconds: i64,
@@ -25,12 +62,67 @@ pub struct UnixTime {
 /// Stores number of seconds since the Unix Epoch (`1970-01-01 00:00:00 UTC`).
 ///
 /// It can represent time from `1970-01-01_00:00:00` to `2106-02-07_06:28:15`.
+///
+/// # Formatting
+/// It's formatted like [`UnixTime`].
+///
+/// ```
+/// use espera::all::UnixTime32;
+///
+/// let ut = UnixTime32::new(915148799);
+/// assert_eq!["1998-12-31_23:59:59", format!["{ut}"]];
+/// assert_eq!["915148799", format!["{ut:#}"]];
+/// assert_eq!["368c0fff", format!["{ut:x}"]];
+/// ```
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UnixTime32 {
     pub seconds: u32,
 }
 
 impl UnixTime {
+    /// The minimum representable `UnixTime`.
+    pub const MIN: UnixTime = UnixTime::new(i64::MIN);
+
+    /// The maximum representable `UnixTime`.
+    pub const MAX: UnixTime = UnixTime::new(i64::MAX);
+
+    /// The Unix Epoch, `1970-01-01 00:00:00 UTC`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// assert_eq![(1970, 1, 1, 0, 0, 0), UnixTime::EPOCH.to_ymdhms()];
+    /// ```
+    pub const EPOCH: UnixTime = UnixTime::new(0);
+
+    /// The GPS Epoch, `1980-01-06 00:00:00 UTC`.
+    ///
+    /// Note that GPS time doesn't have leap seconds, and since 2017 it's
+    /// 18 seconds ahead of UTC, which is not accounted for when converting
+    /// with [`from_epoch_offset`][Self::from_epoch_offset].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// assert_eq![(1980, 1, 6, 0, 0, 0), UnixTime::GPS_EPOCH.to_ymdhms()];
+    /// ```
+    pub const GPS_EPOCH: UnixTime = UnixTime::new(315_964_800);
+
+    /// The J2000 Epoch, approximated as `2000-01-01 12:00:00 UTC`.
+    ///
+    /// The precise epoch is defined in Terrestrial Time, and corresponds to
+    /// `2000-01-01 11:58:55.816 UTC`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// assert_eq![(2000, 1, 1, 12, 0, 0), UnixTime::J2000_EPOCH.to_ymdhms()];
+    /// ```
+    pub const J2000_EPOCH: UnixTime = UnixTime::new(946_728_000);
+
     /// Returns a new `UnixTime` from the given amount of seconds.
     ///
     /// # Examples
@@ -43,10 +135,67 @@ impl UnixTime {
     /// assert_eq!["2106-02-07_06:28:15", UnixTime::new(u32::MAX as i64).to_string()];
     /// assert_eq!["1833-11-24_17:31:45", UnixTime::new(u32::MAX as i64 * -1).to_string()];
     /// ```
-    pub fn new(seconds: i64) -> Self {
+    pub const fn new(seconds: i64) -> Self {
         Self { seconds }
     }
 
+    /// Returns a new `UnixTime` from the given `offset_seconds` relative to a
+    /// different `epoch`, saturating on overflow.
+    ///
+    /// This translates timestamps of other epochs, like
+    /// [`GPS_EPOCH`][Self::GPS_EPOCH] or [`J2000_EPOCH`][Self::J2000_EPOCH],
+    /// into Unix time.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// // one billion GPS seconds
+    /// let t = UnixTime::from_epoch_offset(UnixTime::GPS_EPOCH, 1_000_000_000);
+    /// assert_eq![1_315_964_800, t.seconds];
+    /// assert_eq!["2011-09-14_01:46:40", t.to_string()];
+    ///
+    /// // seconds since 2000-01-01, common in embedded systems
+    /// let y2k = UnixTime::from_ymdhms(2000, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq![y2k, UnixTime::from_epoch_offset(UnixTime::EPOCH, 946_684_800)];
+    /// assert_eq!["2000-01-02_00:00:00", UnixTime::from_epoch_offset(y2k, 86_400).to_string()];
+    ///
+    /// assert_eq![UnixTime::MAX, UnixTime::from_epoch_offset(UnixTime::GPS_EPOCH, i64::MAX)];
+    /// ```
+    #[must_use]
+    pub const fn from_epoch_offset(epoch: UnixTime, offset_seconds: i64) -> UnixTime {
+        Self::new(epoch.seconds.saturating_add(offset_seconds))
+    }
+
+    /// Returns an iterator over `count` times, starting from `start`,
+    /// and separated by the given `step`.
+    ///
+    /// The sub-second part of the `step` is truncated. A negative `step`
+    /// goes backwards in time. The iterator stops early if the next time
+    /// would overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, UnixTime};
+    ///
+    /// let day = UnixTime::from_ymdhms(2024, 6, 1, 0, 0, 0).unwrap();
+    /// let hours: Vec<_> = UnixTime::range(day, Duration::HOUR, 24).collect();
+    /// assert_eq![24, hours.len()];
+    /// assert_eq!["2024-06-01_00:00:00", hours[0].to_string()];
+    /// assert_eq!["2024-06-01_23:00:00", hours[23].to_string()];
+    ///
+    /// // stops on overflow
+    /// let last = UnixTime::new(i64::MAX - 1);
+    /// assert_eq![1, UnixTime::range(last, Duration::SECOND * 2, 5).count()];
+    /// ```
+    pub fn range(start: UnixTime, step: Duration, count: usize) -> impl Iterator<Item = UnixTime> {
+        let step = step.whole_seconds();
+        (0..count).map_while(move |i| {
+            let offset = step.checked_mul(i64::try_from(i).ok()?)?;
+            start.seconds.checked_add(offset).map(UnixTime::new)
+        })
+    }
+
     /// Returns a new `UnixTime` anchored to the current second.
     #[cfg(any(
         feature = "std",
@@ -65,86 +214,322 @@ impl UnixTime {
         }
     }
 
-    /// Returns a `UnixTime` converted to `(year, month, day, hour, minute, second)`.
+    /// Returns the approximate `UnixTime` of the `target` instant, by offsetting
+    /// the `reference_unix` time by the duration between the `reference_instant`
+    /// and the `target` instant.
+    ///
+    /// The reference pair should be captured at the same moment, e.g. by
+    /// calling `Instant::now()` and [`UnixTime::now()`] together. The result
+    /// is floored to the whole second, and saturates at the bounds.
+    ///
+    /// Note that the result doesn't follow any adjustments made to the wall
+    /// clock after the reference pair was captured.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, Instant, UnixTime};
+    ///
+    /// let (ref_instant, ref_unix) = (Instant::now(), UnixTime::new(1_000));
+    ///
+    /// let later = ref_instant + Duration::seconds(90);
+    /// assert_eq![UnixTime::new(1_090), UnixTime::from_instant(ref_instant, ref_unix, later)];
+    ///
+    /// let earlier = ref_instant - Duration::milliseconds(1_500);
+    /// assert_eq![UnixTime::new(998), UnixTime::from_instant(ref_instant, ref_unix, earlier)];
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn from_instant(
+        reference_instant: Instant,
+        reference_unix: UnixTime,
+        target: Instant,
+    ) -> Self {
+        let delta_ns = (target - reference_instant).whole_nanoseconds();
+        // the whole seconds of a `Duration` always fit in an `i64`
+        let delta_s = delta_ns.div_euclid(1_000_000_000) as i64;
+        Self {
+            seconds: reference_unix.seconds.saturating_add(delta_s),
+        }
+    }
+
+    /// Returns the duration elapsed since the Unix Epoch.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, UnixTime};
+    ///
+    /// assert_eq![Duration::minutes(1), UnixTime::new(60).since_epoch()];
+    /// assert_eq![Duration::seconds(-1), UnixTime::new(-1).since_epoch()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn since_epoch(&self) -> Duration {
+        Duration::seconds(self.seconds)
+    }
+
+    /// Returns a new `UnixTime` from the given date and time components.
+    ///
+    /// # Leap seconds
+    /// Since Unix time doesn't count leap seconds, a `second` value of `60`
+    /// is accepted and clamped to `59`, so that the leap second is absorbed
+    /// by the last regular second of the minute.
+    ///
+    /// # Errors
+    /// Returns [`EsperaError::OutOfBounds`] if any component is out of its
+    /// valid range (e.g. `month > 12` or `day > 31`).
     ///
     /// # Examples
     /// ```
     /// use espera::all::UnixTime;
     ///
-    /// assert_eq![(1970, 1, 1, 0, 0, 1), UnixTime::new(1).to_ymdhms()];
-    /// assert_eq![(1969, 12, 31, 23, 59, 59), UnixTime::new(-1).to_ymdhms()];
+    /// assert_eq![UnixTime::new(0), UnixTime::from_ymdhms(1970, 1, 1, 0, 0, 0).unwrap()];
+    /// assert_eq![UnixTime::new(-1), UnixTime::from_ymdhms(1969, 12, 31, 23, 59, 59).unwrap()];
+    /// assert_eq![
+    ///     UnixTime::new(i32::MAX as i64),
+    ///     UnixTime::from_ymdhms(2038, 1, 19, 3, 14, 7).unwrap()
+    /// ];
+    /// assert_eq![
+    ///     UnixTime::from_ymdhms(1998, 12, 31, 23, 59, 59).unwrap(),
+    ///     UnixTime::from_ymdhms(1998, 12, 31, 23, 59, 60).unwrap()
+    /// ];
+    /// assert![UnixTime::from_ymdhms(2023, 2, 29, 0, 0, 0).is_err()];
+    /// assert![UnixTime::from_ymdhms(2023, 13, 1, 0, 0, 0).is_err()];
     /// ```
-    pub const fn to_ymdhms(&self) -> (i32, u8, u8, u8, u8, u8) {
-        let seconds_per_minute: u32 = 60;
-        let minutes_per_hour: u32 = 60;
-        let hours_per_day: u32 = 24;
-        let days_per_year: u32 = 365;
+    pub fn from_ymdhms(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> EsperaResult<UnixTime> {
+        let month_len = Month::from_number(month)
+            .map_err(EsperaError::OutOfBounds)?
+            .len(is_leap_year(year));
+        if day < 1 || day > month_len {
+            return Err(EsperaError::OutOfBounds(
+                "The day must be between 1 and the length of the month.",
+            ));
+        }
+        if hour > 23 {
+            return Err(EsperaError::OutOfBounds(
+                "The hour must be between 0 and 23.",
+            ));
+        }
+        if minute > 59 {
+            return Err(EsperaError::OutOfBounds(
+                "The minute must be between 0 and 59.",
+            ));
+        }
+        if second > 60 {
+            return Err(EsperaError::OutOfBounds(
+                "The second must be between 0 and 60.",
+            ));
+        }
+        // a leap second is clamped to the previous second
+        let second = if second == 60 { 59 } else { second };
 
-        let mut seconds_left = self.seconds.abs();
-        let mut year = if self.seconds >= 0 { 1970 } else { 1969 };
-        let mut leap = is_leap_year(year);
+        let days = days_from_civil(year, month, day);
+        let seconds = days * 86_400 + hour as i64 * 3600 + minute as i64 * 60 + second as i64;
+        Ok(Self { seconds })
+    }
 
-        while seconds_left
-            >= (hours_per_day * minutes_per_hour * seconds_per_minute * days_per_year) as i64
-        {
-            leap = is_leap_year(year);
-            let days_in_year = if leap { 366 } else { 365 };
-            seconds_left -=
-                (hours_per_day * minutes_per_hour * seconds_per_minute * days_in_year) as i64;
+    /// Returns a new `UnixTime` parsed from an [RFC 3339] timestamp,
+    /// like `1998-12-31T23:59:59Z` or `1998-12-31T18:59:59.123-05:00`.
+    ///
+    /// The fractional seconds are discarded, truncating to the whole second,
+    /// and the UTC offset is applied to the resulting time.
+    ///
+    /// A leap second of `60` is clamped to `59`, since Unix time doesn't count
+    /// leap seconds.
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    ///
+    /// # Errors
+    /// Returns [`EsperaError::Parse`] if the format is invalid, or
+    /// [`EsperaError::OutOfBounds`] if any component is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// assert_eq![UnixTime::new(915148799), UnixTime::from_rfc3339("1998-12-31T23:59:59Z").unwrap()];
+    /// assert_eq![UnixTime::new(915148799), UnixTime::from_rfc3339("1998-12-31T23:59:60Z").unwrap()];
+    /// assert_eq![
+    ///     UnixTime::new(915148799),
+    ///     UnixTime::from_rfc3339("1998-12-31T18:59:59.999-05:00").unwrap()
+    /// ];
+    /// assert![UnixTime::from_rfc3339("1998-12-31T23:59:59").is_err()];
+    /// ```
+    pub fn from_rfc3339(s: &str) -> EsperaResult<UnixTime> {
+        let b = s.as_bytes();
+        let mut i = 0;
+        let (y, m, d, h, min, sec) = parse_ymdhms(b, &mut i, b'T')?;
+        let ut = Self::from_ymdhms(y, m, d, h, min, sec)?;
 
-            if self.seconds >= 0 {
-                year += 1;
-            } else {
-                year -= 1;
+        let offset = match b.get(i) {
+            Some(b'Z') => {
+                i += 1;
+                0
             }
-        }
-
-        let mut month = Month::January;
-        while seconds_left
-            >= (hours_per_day * minutes_per_hour * seconds_per_minute * month.len(leap) as u32)
-                as i64
-        {
-            seconds_left -=
-                (hours_per_day * minutes_per_hour * seconds_per_minute * month.len(leap) as u32)
-                    as i64;
-            month = month.next();
-        }
+            Some(sign @ (b'+' | b'-')) => {
+                let sign = if *sign == b'-' { -1 } else { 1 };
+                i += 1;
+                let oh = parse_digits(b, &mut i, 2, 2)?;
+                parse_byte(b, &mut i, b':')?;
+                let om = parse_digits(b, &mut i, 2, 2)?;
+                if oh > 23 || om > 59 {
+                    return Err(EsperaError::OutOfBounds("Invalid UTC offset."));
+                }
+                sign * (oh as i64 * 3600 + om as i64 * 60)
+            }
+            _ => return Err(EsperaError::Parse("Expected a UTC offset.")),
+        };
+        parse_end(b, i)?;
+        Ok(Self::new(ut.seconds - offset))
+    }
 
-        let day = (seconds_left / (hours_per_day * minutes_per_hour * seconds_per_minute) as i64)
-            as u8
-            + 1;
-        seconds_left %= (hours_per_day * minutes_per_hour * seconds_per_minute) as i64;
-
-        let hour = seconds_left / (minutes_per_hour * seconds_per_minute) as i64;
-        seconds_left %= (minutes_per_hour * seconds_per_minute) as i64;
-
-        let minute = seconds_left / seconds_per_minute as i64;
-        let second = seconds_left % seconds_per_minute as i64;
-
-        if self.seconds >= 0 {
-            (
-                year,
-                month.number(),
-                day,
-                hour as u8,
-                minute as u8,
-                second as u8,
-            )
+    /// Returns a `UnixTime` converted to `(year, month, day, hour, minute, second)`.
+    ///
+    /// The dates follow the proleptic Gregorian calendar, with astronomical
+    /// year numbering, as in ISO 8601: the year `0` is 1 BCE, `-1` is 2 BCE,
+    /// and so on.
+    ///
+    /// The `second` is never `60`, since Unix time doesn't count leap seconds.
+    ///
+    /// The supported range goes from `-2147483648-01-01 00:00:00` to
+    /// `2147483647-12-31 23:59:59`, which are the years representable by an
+    /// `i32`. Times outside of it saturate to its bounds. The [`Display`]
+    /// implementation doesn't have this limitation.
+    ///
+    /// [`Display`]: fmt::Display
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// assert_eq![(1970, 1, 1, 0, 0, 1), UnixTime::new(1).to_ymdhms()];
+    /// assert_eq![(1969, 12, 31, 23, 59, 59), UnixTime::new(-1).to_ymdhms()];
+    /// assert_eq![(0, 1, 1, 0, 0, 0), UnixTime::new(-62167219200).to_ymdhms()];
+    /// assert_eq![(-1, 12, 31, 23, 59, 59), UnixTime::new(-62167219201).to_ymdhms()];
+    /// assert_eq![(i32::MIN, 1, 1, 0, 0, 0), UnixTime::new(i64::MIN / 2).to_ymdhms()];
+    /// assert_eq![(i32::MAX, 12, 31, 23, 59, 59), UnixTime::MAX.to_ymdhms()];
+    ///
+    /// // exact boundaries before the epoch
+    /// assert_eq![(1969, 12, 31, 23, 59, 0), UnixTime::new(-60).to_ymdhms()];
+    /// assert_eq![(1969, 12, 31, 23, 0, 0), UnixTime::new(-3_600).to_ymdhms()];
+    /// assert_eq![(1969, 12, 31, 0, 0, 0), UnixTime::new(-86_400).to_ymdhms()];
+    /// assert_eq![(1969, 12, 30, 23, 59, 59), UnixTime::new(-86_401).to_ymdhms()];
+    /// assert_eq![(1969, 1, 1, 0, 0, 0), UnixTime::new(-31_536_000).to_ymdhms()];
+    /// assert_eq![(1968, 3, 1, 0, 0, 0), UnixTime::new(-57_974_400).to_ymdhms()];
+    /// assert_eq![(1968, 2, 29, 23, 59, 59), UnixTime::new(-57_974_401).to_ymdhms()];
+    /// ```
+    ///
+    /// Every second of the two days before the epoch, and the seconds around
+    /// each hour boundary of the four years before it, are in range and
+    /// round-trip through [`from_ymdhms`][Self::from_ymdhms]:
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// let check = |seconds| {
+    ///     let (y, mo, d, h, mi, s) = UnixTime::new(seconds).to_ymdhms();
+    ///     assert![h < 24 && mi < 60 && s < 60];
+    ///     let round_trip = UnixTime::from_ymdhms(y, mo, d, h, mi, s).unwrap();
+    ///     assert_eq![UnixTime::new(seconds), round_trip];
+    /// };
+    /// (-2 * 86_400..=0).for_each(check);
+    /// for hour in -4 * 8_766..0 {
+    ///     (hour * 3_600 - 1..=hour * 3_600 + 1).for_each(check);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn to_ymdhms(&self) -> (i32, u8, u8, u8, u8, u8) {
+        let (year, month, day, hour, minute, second) = self.to_ymdhms_wide();
+        if year < i32::MIN as i64 {
+            (i32::MIN, 1, 1, 0, 0, 0)
+        } else if year > i32::MAX as i64 {
+            (i32::MAX, 12, 31, 23, 59, 59)
         } else {
-            (
-                year,
-                13 - month.number(),
-                Month::December.previous_nth(month.index()).len(leap) - day + 1,
-                23 - hour as u8,
-                59 - minute as u8,
-                60 - second as u8,
-            )
+            (year as i32, month, day, hour, minute, second)
         }
     }
+
+    /// Returns the year, saturating like [`to_ymdhms`][Self::to_ymdhms].
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// assert_eq![1970, UnixTime::new(0).year()];
+    /// assert_eq![1969, UnixTime::new(-1).year()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn year(&self) -> i32 {
+        self.to_ymdhms().0
+    }
+
+    /// Returns the quarter of the year, from 1 to 4.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// let march = UnixTime::from_ymdhms(2024, 3, 31, 23, 59, 59).unwrap();
+    /// let april = UnixTime::from_ymdhms(2024, 4, 1, 0, 0, 0).unwrap();
+    /// let july = UnixTime::from_ymdhms(2024, 7, 4, 12, 0, 0).unwrap();
+    /// let december = UnixTime::from_ymdhms(2024, 12, 31, 0, 0, 0).unwrap();
+    /// assert_eq![1, march.quarter()];
+    /// assert_eq![2, april.quarter()];
+    /// assert_eq![3, july.quarter()];
+    /// assert_eq![4, december.quarter()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn quarter(&self) -> u8 {
+        (self.to_ymdhms().1 - 1) / 3 + 1
+    }
+
+    /// Returns the half of the year, 1 or 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime;
+    ///
+    /// let march = UnixTime::from_ymdhms(2024, 3, 15, 0, 0, 0).unwrap();
+    /// let june = UnixTime::from_ymdhms(2024, 6, 30, 23, 59, 59).unwrap();
+    /// let july = UnixTime::from_ymdhms(2024, 7, 1, 0, 0, 0).unwrap();
+    /// assert_eq![1, march.half()];
+    /// assert_eq![1, june.half()];
+    /// assert_eq![2, july.half()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn half(&self) -> u8 {
+        (self.to_ymdhms().1 - 1) / 6 + 1
+    }
 }
 
 // private functions
 impl UnixTime {
+    // Like `to_ymdhms`, but with an `i64` year that can represent any time.
+    const fn to_ymdhms_wide(self) -> (i64, u8, u8, u8, u8, u8) {
+        let days = self.seconds.div_euclid(86_400);
+        let secs = self.seconds.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        let (hour, minute, second) = (secs / 3600, secs % 3600 / 60, secs % 60);
+        (year, month, day, hour as u8, minute as u8, second as u8)
+    }
+
+    // Formats the time as `YYYY-MM-DD_HH:MM:SS`, with a leading `-` for
+    // negative years, and at least 4 digits for the year.
+    fn write_ymdhms(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (y, m, d, h, min, s) = self.to_ymdhms_wide();
+        let sign = if y < 0 { "-" } else { "" };
+        let y = y.unsigned_abs();
+        write![f, "{sign}{y:04}-{m:02}-{d:02}_{h:02}:{min:02}:{s:02}"]
+    }
+
     // Returns the number of seconds since `1970-01-01 00:00:00 UTC`.
     #[cfg(feature = "std")]
     fn unix_time_64() -> i64 {
@@ -171,6 +556,29 @@ impl UnixTime {
 }
 
 impl UnixTime32 {
+    /// The minimum representable `UnixTime32`, which is the Unix Epoch.
+    pub const MIN: UnixTime32 = UnixTime32::new(0);
+
+    /// The maximum representable `UnixTime32`, `2106-02-07 06:28:15 UTC`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime32;
+    ///
+    /// assert_eq![(2106, 2, 7, 6, 28, 15), UnixTime32::MAX.to_ymdhms()];
+    /// ```
+    pub const MAX: UnixTime32 = UnixTime32::new(u32::MAX);
+
+    /// The Unix Epoch, `1970-01-01 00:00:00 UTC`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime32;
+    ///
+    /// assert_eq![(1970, 1, 1, 0, 0, 0), UnixTime32::EPOCH.to_ymdhms()];
+    /// ```
+    pub const EPOCH: UnixTime32 = UnixTime32::new(0);
+
     /// Returns a new `UnixTime32` from the given amount of seconds.
     ///
     /// # Examples
@@ -180,10 +588,34 @@ impl UnixTime32 {
     /// assert_eq!["1970-01-01_00:00:00", UnixTime32::new(0).to_string()];
     /// assert_eq!["2106-02-07_06:28:15", UnixTime32::new(u32::MAX).to_string()];
     /// ```
-    pub fn new(seconds: u32) -> Self {
+    pub const fn new(seconds: u32) -> Self {
         Self { seconds }
     }
 
+    /// Returns a new `UnixTime32` from the given `UnixTime`, clamping negative
+    /// values to `0` and values greater than [`u32::MAX`] to `u32::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{UnixTime, UnixTime32};
+    ///
+    /// assert_eq![UnixTime32::new(0), UnixTime32::saturating_from(UnixTime::new(-1))];
+    /// assert_eq![UnixTime32::new(60), UnixTime32::saturating_from(UnixTime::new(60))];
+    /// assert_eq!["2106-02-07_06:28:15", UnixTime32::saturating_from(UnixTime::new(i64::MAX)).to_string()];
+    /// ```
+    #[must_use]
+    pub const fn saturating_from(ut: UnixTime) -> Self {
+        Self {
+            seconds: if ut.seconds < 0 {
+                0
+            } else if ut.seconds > u32::MAX as i64 {
+                u32::MAX
+            } else {
+                ut.seconds as u32
+            },
+        }
+    }
+
     /// Returns a new `UnixTime32` anchored to the current second.
     #[cfg(any(
         feature = "std",
@@ -202,33 +634,93 @@ impl UnixTime32 {
         }
     }
 
+    /// Returns the duration elapsed since the Unix Epoch.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::{Duration, UnixTime32};
+    ///
+    /// assert_eq![Duration::minutes(1), UnixTime32::new(60).since_epoch()];
+    /// assert_eq![Duration::seconds(u32::MAX.into()), UnixTime32::new(u32::MAX).since_epoch()];
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn since_epoch(&self) -> Duration {
+        Duration::seconds(self.seconds as i64)
+    }
+
+    /// Returns a new `UnixTime32` from the given date and time components.
+    ///
+    /// The date must be in the representable range from
+    /// `1970-01-01_00:00:00` to `2106-02-07_06:28:15`.
+    ///
+    /// A leap `second` of `60` is clamped to `59`, like in
+    /// [`UnixTime::from_ymdhms`].
+    ///
+    /// # Errors
+    /// Returns [`EsperaError::OutOfBounds`] if any component is out of its
+    /// valid range, or if the date is outside the representable range.
+    ///
+    /// # Examples
+    /// ```
+    /// use espera::all::UnixTime32;
+    ///
+    /// assert_eq![UnixTime32::new(0), UnixTime32::from_ymdhms(1970, 1, 1, 0, 0, 0).unwrap()];
+    /// assert_eq![
+    ///     UnixTime32::new(u32::MAX),
+    ///     UnixTime32::from_ymdhms(2106, 2, 7, 6, 28, 15).unwrap()
+    /// ];
+    /// assert![UnixTime32::from_ymdhms(2106, 2, 7, 6, 28, 16).is_err()];
+    /// assert![UnixTime32::from_ymdhms(1969, 12, 31, 23, 59, 59).is_err()];
+    /// ```
+    pub fn from_ymdhms(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> EsperaResult<UnixTime32> {
+        let ut = UnixTime::from_ymdhms(year.into(), month, day, hour, minute, second)?;
+        let seconds = u32::try_from(ut.seconds).map_err(|_| {
+            EsperaError::OutOfBounds(
+                "The date must be between 1970-01-01_00:00:00 and 2106-02-07_06:28:15.",
+            )
+        })?;
+        Ok(Self { seconds })
+    }
+
     /// Returns a `UnixTime32` converted to `(year, month, day, hour, minute, second)`.
     ///
+    /// The `second` is never `60`, since Unix time doesn't count leap seconds.
+    ///
     /// # Examples
     /// ```
     /// use espera::all::UnixTime32;
     ///
     /// assert_eq![(1970, 1, 1, 0, 0, 1), UnixTime32::new(1).to_ymdhms()];
     /// assert_eq![(2038, 1, 19, 3, 14, 7), UnixTime32::new(i32::MAX as u32).to_ymdhms()];
+    /// assert_eq![(1972, 1, 6, 0, 22, 45), UnixTime32::new(63505365).to_ymdhms()];
+    /// assert_eq![(2106, 2, 7, 6, 28, 15), UnixTime32::MAX.to_ymdhms()];
     /// ```
+    #[must_use]
     pub const fn to_ymdhms(&self) -> (u16, u8, u8, u8, u8, u8) {
         let seconds_per_minute: u32 = 60;
         let minutes_per_hour: u32 = 60;
         let hours_per_day: u32 = 24;
-        let days_per_year: u32 = 365;
 
         let mut seconds_left = self.seconds;
         let mut year = 1970;
-        let mut leap = is_leap_year(year);
-
-        while seconds_left
-            >= (hours_per_day * minutes_per_hour * seconds_per_minute * days_per_year)
-        {
+        loop {
+            let seconds_in_year =
+                hours_per_day * minutes_per_hour * seconds_per_minute * days_in_year(year) as u32;
+            if seconds_left < seconds_in_year {
+                break;
+            }
+            seconds_left -= seconds_in_year;
             year += 1;
-            leap = is_leap_year(year);
-            let days_in_year = if leap { 366 } else { 365 };
-            seconds_left -= hours_per_day * minutes_per_hour * seconds_per_minute * days_in_year;
         }
+        let leap = is_leap_year(year);
 
         let mut month = Month::January;
         while seconds_left
@@ -286,24 +778,49 @@ impl UnixTime32 {
 
 impl fmt::Display for UnixTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (y, m, d, h, min, s) = self.to_ymdhms();
-        write![f, "{y:04}-{m:02}-{d:02}_{h:02}:{min:02}:{s:02}"]
+        if f.alternate() {
+            write![f, "{}", self.seconds]
+        } else {
+            self.write_ymdhms(f)
+        }
+    }
+}
+impl fmt::LowerHex for UnixTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.seconds, f)
+    }
+}
+impl fmt::UpperHex for UnixTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.seconds, f)
     }
 }
 impl fmt::Debug for UnixTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (y, m, d, h, min, s) = self.to_ymdhms();
-        write![
-            f,
-            "UnixTime {{ {y:04}-{m:02}-{d:02}_{h:02}:{min:02}:{s:02} }}"
-        ]
+        f.write_str("UnixTime { ")?;
+        self.write_ymdhms(f)?;
+        f.write_str(" }")
     }
 }
 
 impl fmt::Display for UnixTime32 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (y, m, d, h, min, s) = self.to_ymdhms();
-        write![f, "{y:04}-{m:02}-{d:02}_{h:02}:{min:02}:{s:02}"]
+        if f.alternate() {
+            write![f, "{}", self.seconds]
+        } else {
+            let (y, m, d, h, min, s) = self.to_ymdhms();
+            write![f, "{y:04}-{m:02}-{d:02}_{h:02}:{min:02}:{s:02}"]
+        }
+    }
+}
+impl fmt::LowerHex for UnixTime32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.seconds, f)
+    }
+}
+impl fmt::UpperHex for UnixTime32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.seconds, f)
     }
 }
 
@@ -317,6 +834,194 @@ impl fmt::Debug for UnixTime32 {
     }
 }
 
+/// Parses a `UnixTime` from its [`Display`][fmt::Display] format
+/// `YYYY-MM-DD_HH:MM:SS`, with an optional fractional seconds suffix.
+///
+/// The fractional seconds (e.g. `.123`) are validated and then discarded,
+/// truncating the time to the whole second.
+///
+/// A leap second of `60` is clamped to `59`, since Unix time doesn't count
+/// leap seconds.
+///
+/// The year can have at most 9 digits, so only the times from the year
+/// `-999999999` to `999999999` round-trip through [`Display`][fmt::Display],
+/// which can print years of up to 12 digits.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime;
+///
+/// let max = UnixTime::from_ymdhms(999_999_999, 12, 31, 23, 59, 59).unwrap();
+/// let min = UnixTime::from_ymdhms(-999_999_999, 1, 1, 0, 0, 0).unwrap();
+/// assert_eq![max, max.to_string().parse::<UnixTime>().unwrap()];
+/// assert_eq![min, min.to_string().parse::<UnixTime>().unwrap()];
+/// assert_eq!["292277026596-12-04_15:30:07", UnixTime::MAX.to_string()];
+/// assert![UnixTime::MAX.to_string().parse::<UnixTime>().is_err()];
+///
+/// assert_eq![UnixTime::new(1), "1970-01-01_00:00:01".parse::<UnixTime>().unwrap()];
+/// assert_eq![UnixTime::new(1), "1970-01-01_00:00:01.999".parse::<UnixTime>().unwrap()];
+/// assert_eq![UnixTime::new(-1), "1969-12-31_23:59:59".parse::<UnixTime>().unwrap()];
+/// assert_eq![UnixTime::new(915148799), "1998-12-31_23:59:60".parse::<UnixTime>().unwrap()];
+/// assert!["1970-01-01_00:00:01.".parse::<UnixTime>().is_err()];
+/// assert!["1970-01-01_00:00:01.9x".parse::<UnixTime>().is_err()];
+/// assert!["1970-01-01 00:00:01".parse::<UnixTime>().is_err()];
+/// ```
+impl FromStr for UnixTime {
+    type Err = EsperaError;
+
+    fn from_str(s: &str) -> EsperaResult<UnixTime> {
+        let b = s.as_bytes();
+        let mut i = 0;
+        let (y, m, d, h, min, sec) = parse_ymdhms(b, &mut i, b'_')?;
+        parse_end(b, i)?;
+        UnixTime::from_ymdhms(y, m, d, h, min, sec)
+    }
+}
+
+/// Parses a `UnixTime` like its [`FromStr`] implementation.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime;
+///
+/// for seconds in [0, 1, -1, 951782400, 4102444799, -62167219201] {
+///     let ut = UnixTime::new(seconds);
+///     assert_eq![ut, UnixTime::try_from(ut.to_string().as_str()).unwrap()];
+/// }
+/// assert![UnixTime::try_from("1970-01-01").is_err()];
+/// assert![UnixTime::try_from("1970-01-01_00:00:00Z").is_err()];
+/// ```
+impl TryFrom<&str> for UnixTime {
+    type Error = EsperaError;
+
+    fn try_from(s: &str) -> EsperaResult<UnixTime> {
+        s.parse()
+    }
+}
+
+/// Parses a `UnixTime32` from its [`Display`][fmt::Display] format
+/// `YYYY-MM-DD_HH:MM:SS`, with an optional fractional seconds suffix.
+///
+/// It follows the same rules as the [`FromStr`] implementation of
+/// [`UnixTime`], and the date must be between `1970-01-01_00:00:00`
+/// and `2106-02-07_06:28:15`.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime32;
+///
+/// assert_eq![UnixTime32::new(1), "1970-01-01_00:00:01".parse::<UnixTime32>().unwrap()];
+/// assert_eq![UnixTime32::MAX, "2106-02-07_06:28:15".parse::<UnixTime32>().unwrap()];
+/// assert!["2106-02-07_06:28:16".parse::<UnixTime32>().is_err()];
+/// assert!["1969-12-31_23:59:59".parse::<UnixTime32>().is_err()];
+/// assert!["-0001-01-01_00:00:00".parse::<UnixTime32>().is_err()];
+/// ```
+impl FromStr for UnixTime32 {
+    type Err = EsperaError;
+
+    fn from_str(s: &str) -> EsperaResult<UnixTime32> {
+        let b = s.as_bytes();
+        let mut i = 0;
+        let (y, m, d, h, min, sec) = parse_ymdhms(b, &mut i, b'_')?;
+        parse_end(b, i)?;
+        let y = u16::try_from(y).map_err(|_| {
+            EsperaError::OutOfBounds(
+                "The date must be between 1970-01-01_00:00:00 and 2106-02-07_06:28:15.",
+            )
+        })?;
+        UnixTime32::from_ymdhms(y, m, d, h, min, sec)
+    }
+}
+
+/// Parses a `UnixTime32` like its [`FromStr`] implementation.
+///
+/// # Examples
+/// ```
+/// use espera::all::UnixTime32;
+///
+/// for seconds in [0, 1, 951782400, u32::MAX] {
+///     let ut = UnixTime32::new(seconds);
+///     assert_eq![ut, UnixTime32::try_from(ut.to_string().as_str()).unwrap()];
+/// }
+/// assert![UnixTime32::try_from("1970-01-01_00:00").is_err()];
+/// ```
+impl TryFrom<&str> for UnixTime32 {
+    type Error = EsperaError;
+
+    fn try_from(s: &str) -> EsperaResult<UnixTime32> {
+        s.parse()
+    }
+}
+
+// Parses a date and time in the form `[-]YYYY-MM-DD?HH:MM:SS[.fff]`,
+// where `?` is the provided `separator`, starting at the position `i`.
+//
+// The optional fractional seconds are validated and discarded.
+fn parse_ymdhms(b: &[u8], i: &mut usize, separator: u8) -> EsperaResult<(i32, u8, u8, u8, u8, u8)> {
+    let negative = b.get(*i) == Some(&b'-');
+    if negative {
+        *i += 1;
+    }
+    let year = parse_digits(b, i, 1, 9)? as i32;
+    let year = if negative { -year } else { year };
+    parse_byte(b, i, b'-')?;
+    let month = parse_digits(b, i, 2, 2)? as u8;
+    parse_byte(b, i, b'-')?;
+    let day = parse_digits(b, i, 2, 2)? as u8;
+    parse_byte(b, i, separator)?;
+    let hour = parse_digits(b, i, 2, 2)? as u8;
+    parse_byte(b, i, b':')?;
+    let minute = parse_digits(b, i, 2, 2)? as u8;
+    parse_byte(b, i, b':')?;
+    let second = parse_digits(b, i, 2, 2)? as u8;
+
+    if b.get(*i) == Some(&b'.') {
+        *i += 1;
+        let _fraction = parse_digits(b, i, 1, usize::MAX)?;
+    }
+    Ok((year, month, day, hour, minute, second))
+}
+
+// Checks that the position `i` is at the end of the bytes.
+fn parse_end(b: &[u8], i: usize) -> EsperaResult<()> {
+    if i == b.len() {
+        Ok(())
+    } else {
+        Err(EsperaError::Parse("Unexpected trailing characters."))
+    }
+}
+
+// Consumes the `expected` byte at the position `i`.
+fn parse_byte(b: &[u8], i: &mut usize, expected: u8) -> EsperaResult<()> {
+    if b.get(*i) == Some(&expected) {
+        *i += 1;
+        Ok(())
+    } else {
+        Err(EsperaError::Parse("Invalid date-time format."))
+    }
+}
+
+// Consumes between `min` and `max` ASCII digits starting at the position `i`,
+// and returns their value, saturating at [`u32::MAX`].
+fn parse_digits(b: &[u8], i: &mut usize, min: usize, max: usize) -> EsperaResult<u32> {
+    let start = *i;
+    let mut value = 0_u32;
+    while *i - start < max {
+        match b.get(*i) {
+            Some(d) if d.is_ascii_digit() => {
+                value = value.saturating_mul(10).saturating_add((d - b'0') as u32);
+                *i += 1;
+            }
+            _ => break,
+        }
+    }
+    if *i - start < min {
+        Err(EsperaError::Parse("Expected a digit."))
+    } else {
+        Ok(value)
+    }
+}
+
 impl From<UnixTime32> for UnixTime {
     fn from(ut: UnixTime32) -> UnixTime {
         UnixTime {